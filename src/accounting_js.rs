@@ -0,0 +1,263 @@
+//! Settings objects of [accounting.js](https://openexchangerates.github.io/accounting.js/),
+//! the `settings.currency` shape with `%s`/`%v` format strings.
+//!
+//! In accounting.js format strings, the first `%s` is the symbol and the first `%v` the value.
+//! The format is either one string, used for positive values and zero, with a `-` moved in front
+//! of `%v` for negative values, or an object with `pos`, `neg` and `zero` strings.
+//!
+//! Like accounting.js, a format without `%v` falls back to the default `%s%v`, and a missing
+//! `neg` or `zero` is derived from `pos`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{Accounting, AccountingJsFormat, AccountingJsSettings};
+//! let settings = AccountingJsSettings {
+//!     symbol: "€".to_string(),
+//!     format: AccountingJsFormat::Object {
+//!         pos: "%v %s".to_string(),
+//!         neg: Some("(%v %s)".to_string()),
+//!         zero: Some("-- %s".to_string()),
+//!     },
+//!     decimal: ",".to_string(),
+//!     thousand: ".".to_string(),
+//!     precision: 2,
+//! };
+//! let ac = Accounting::from_accounting_js_settings(&settings);
+//! assert_eq!(ac.format_money(-1234.5), "(1.234,50 €)");
+//! assert_eq!(ac.format_money(0), "-- €");
+//! assert_eq!(ac.to_accounting_js_settings(), settings);
+//! ```
+
+use crate::template::Token;
+use crate::Accounting;
+
+/// The `format` of an accounting.js settings object.
+///
+/// With feature `serde`, it is a JSON string or a `{pos, neg, zero}` object like in accounting.js.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum AccountingJsFormat {
+    /// One format string like `%s %v`, `neg` and `zero` are derived from it.
+    Single(String),
+    /// Separate format strings for positive values, negative values and zero.
+    Object {
+        pos: String,
+        #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+        neg: Option<String>,
+        #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+        zero: Option<String>,
+    },
+}
+
+/// The currency settings object of accounting.js, like
+/// `{ symbol: "$", format: "%s%v", decimal: ".", thousand: ",", precision: 2 }`.
+///
+/// With feature `serde`, missing fields take the accounting.js defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AccountingJsSettings {
+    pub symbol: String,
+    pub format: AccountingJsFormat,
+    pub decimal: String,
+    pub thousand: String,
+    pub precision: usize,
+}
+
+impl Default for AccountingJsSettings {
+    /// The defaults of accounting.js.
+    fn default() -> Self {
+        AccountingJsSettings {
+            symbol: "$".to_string(),
+            format: AccountingJsFormat::Single("%s%v".to_string()),
+            decimal: ".".to_string(),
+            thousand: ",".to_string(),
+            precision: 2,
+        }
+    }
+}
+
+impl AccountingJsFormat {
+    /// Returns the `pos`, `neg` and `zero` format strings the way accounting.js resolves them.
+    pub fn resolve(&self) -> (String, String, String) {
+        let (pos, neg, zero) = match self {
+            AccountingJsFormat::Single(format) => (format, None, None),
+            AccountingJsFormat::Object { pos, neg, zero } => (pos, neg.as_ref(), zero.as_ref()),
+        };
+        if !pos.contains("%v") {
+            let default = "%s%v".to_string();
+            return (default.clone(), negative(&default), default);
+        }
+        let neg = neg.cloned().unwrap_or_else(|| negative(pos));
+        let zero = zero.cloned().unwrap_or_else(|| pos.clone());
+        (pos.clone(), neg, zero)
+    }
+}
+
+/// Returns the negative format accounting.js derives from a single format string.
+fn negative(format: &str) -> String {
+    format.replacen('-', "", 1).replacen("%v", "-%v", 1)
+}
+
+/// Turns an accounting.js format string into a template, replacing the first `%s` and `%v`.
+fn template(format: &str) -> String {
+    let escaped = format.replace('{', "{{").replace('}', "}}");
+    escaped.replacen("%s", "{s}", 1).replacen("%v", "{v}", 1)
+}
+
+impl From<&AccountingJsSettings> for Accounting {
+    fn from(settings: &AccountingJsSettings) -> Self {
+        let (pos, neg, zero) = settings.format.resolve();
+        // Braces are escaped and `{s}` and `{v}` are known placeholders.
+        Accounting::new(
+            &settings.symbol,
+            settings.precision,
+            &settings.thousand,
+            &settings.decimal,
+            &template(&pos),
+            &template(&neg),
+            &template(&zero),
+        )
+    }
+}
+
+impl Accounting {
+    /// Create Accounting from an accounting.js currency settings object, see
+    /// [accounting_js](crate::accounting_js).
+    pub fn from_accounting_js_settings(settings: &AccountingJsSettings) -> Self {
+        Accounting::from(settings)
+    }
+
+    /// Returns the accounting.js currency settings object of the symbol, separators, precision
+    /// and format strings.
+    ///
+    /// `{c}` and `{n}` are written as the code and name, `{sign}` as `-` in the negative format
+    /// only. A single format string is returned when accounting.js would derive the others from
+    /// it. Grouping, rounding and compact notation are left out, accounting.js groups by three.
+    /// The precision is the maximum number of fraction digits. accounting.js always pads to it,
+    /// so the minimum number of fraction digits is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, AccountingJsFormat};
+    /// let mut ac = Accounting::for_currency("USD").unwrap();
+    /// ac.set_format_negative("{s}-{v}").unwrap();
+    /// assert_eq!(ac.to_accounting_js_settings().format, AccountingJsFormat::Single("%s%v".to_string()));
+    /// ```
+    pub fn to_accounting_js_settings(&self) -> AccountingJsSettings {
+        let format = |tokens: &[Token], negative: bool| {
+            let mut result = String::new();
+            for token in tokens {
+                match token {
+                    Token::Literal(s) => result.push_str(s),
+                    Token::Symbol => result.push_str("%s"),
+                    Token::Code => result.push_str(&self.code),
+                    Token::Name => result.push_str(&self.name),
+                    Token::Sign if negative => result.push('-'),
+                    Token::Sign => {}
+                    Token::Value => result.push_str("%v"),
+                }
+            }
+            result
+        };
+        let pos = format(self.format_positive.tokens(), false);
+        let neg = format(self.format_negative.tokens(), true);
+        let zero = format(self.format_zero.tokens(), false);
+
+        let format = if pos.contains("%v") && neg == negative(&pos) && zero == pos {
+            AccountingJsFormat::Single(pos)
+        } else {
+            AccountingJsFormat::Object { pos, neg: Some(neg), zero: Some(zero) }
+        };
+        AccountingJsSettings {
+            symbol: self.symbol.clone(),
+            format,
+            decimal: self.decimal.clone(),
+            thousand: self.thousand.clone(),
+            precision: self.precision,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{template, AccountingJsFormat, AccountingJsSettings};
+    use crate::Accounting;
+
+    #[test]
+    fn from_settings_test() {
+        let ac = Accounting::from_accounting_js_settings(&AccountingJsSettings::default());
+        assert_eq!(ac.format_money(1234.5), "$1,234.50");
+        assert_eq!(ac.format_money(-1234.5), "$-1,234.50");
+
+        let mut settings = AccountingJsSettings {
+            symbol: "GBP".to_string(),
+            format: AccountingJsFormat::Single("%v {%s}".to_string()),
+            precision: 0,
+            ..AccountingJsSettings::default()
+        };
+        let ac = Accounting::from_accounting_js_settings(&settings);
+        assert_eq!(ac.format_money(5), "5 {GBP}");
+        assert_eq!(ac.format_money(-5), "-5 {GBP}");
+
+        // Without `%v` accounting.js uses its default format.
+        settings.format = AccountingJsFormat::Object { pos: "%s".to_string(), neg: None, zero: None };
+        assert_eq!(Accounting::from_accounting_js_settings(&settings).format_money(-5), "GBP-5");
+        settings.format = AccountingJsFormat::Object { pos: "%s %v".to_string(), neg: None, zero: None };
+        let ac = Accounting::from_accounting_js_settings(&settings);
+        assert_eq!((ac.format_money(-5), ac.format_money(0)), ("GBP -5".to_string(), "GBP 0".to_string()));
+    }
+
+    #[test]
+    fn template_test() {
+        assert_eq!(template("%s %v"), "{s} {v}");
+        assert_eq!(template("%v %s %s%%"), "{v} {s} %s%%");
+        assert_eq!(template("{%v}"), "{{{v}}}");
+    }
+
+    #[test]
+    fn to_settings_test() {
+        let ac = Accounting::new_from("$", 2);
+        assert_eq!(ac.to_accounting_js_settings().format, AccountingJsFormat::Object {
+            pos: "%s%v".to_string(),
+            neg: Some("-%s%v".to_string()),
+            zero: Some("%s%v".to_string()),
+        });
+
+        let mut ac = Accounting::for_currency("SEK").unwrap();
+        ac.set_format("{v} {c}{sign}").unwrap();
+        let settings = ac.to_accounting_js_settings();
+        assert_eq!(settings.format, AccountingJsFormat::Object {
+            pos: "%v SEK".to_string(),
+            neg: Some("%v SEK-".to_string()),
+            zero: Some("%v SEK".to_string()),
+        });
+        assert_eq!((settings.thousand.as_str(), settings.decimal.as_str()), (" ", ","));
+        let copy = Accounting::from_accounting_js_settings(&settings);
+        for value in [1234.5, -1234.5, 0.0] {
+            assert_eq!(copy.format_money(value), ac.format_money(value));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let json = r#"{"symbol": "€", "format": {"pos": "%s %v", "neg": "%s (%v)"}, "precision": 0}"#;
+        let settings: AccountingJsSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.format, AccountingJsFormat::Object {
+            pos: "%s %v".to_string(),
+            neg: Some("%s (%v)".to_string()),
+            zero: None,
+        });
+        assert_eq!(settings.thousand, ",");
+        assert_eq!(Accounting::from_accounting_js_settings(&settings).format_money(-5), "€ (5)");
+
+        let settings = AccountingJsSettings::default();
+        let json = serde_json::to_string(&settings).unwrap();
+        assert_eq!(json, r#"{"symbol":"$","format":"%s%v","decimal":".","thousand":",","precision":2}"#);
+    }
+}
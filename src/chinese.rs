@@ -0,0 +1,361 @@
+//! Chinese financial uppercase numerals (大写金额), as required on invoices and bank slips.
+//!
+//! [format_chinese_uppercase] writes an amount of 人民币 like `人民币壹仟贰佰叁拾肆元伍角陆分`,
+//! and [parse_chinese_uppercase] reads it back into [Money] in `CNY`.
+//!
+//! - Skipped positions are written as a single `零`, e.g. `壹仟零伍元`.
+//! - Amounts without 角 and 分 end with `整`.
+//! - Negative amounts start with `负`.
+//! - Groups above `亿` repeat the units, `壹万亿` is 10^12.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{format_chinese_uppercase, parse_chinese_uppercase, Money};
+//! assert_eq!(format_chinese_uppercase(1234.56).unwrap(), "人民币壹仟贰佰叁拾肆元伍角陆分");
+//! assert_eq!(format_chinese_uppercase(100010).unwrap(), "人民币壹拾万零壹拾元整");
+//!
+//! let money = parse_chinese_uppercase("人民币负壹仟零伍元零陆分").unwrap();
+//! assert_eq!(money, Money::from_minor(-100506, "CNY").unwrap());
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::format_number::{FormatNumber, RoundingMode};
+use crate::money::Money;
+
+type Result<T> = std::result::Result<T, ChineseError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChineseError {
+    /// A NaN or infinite number was used.
+    NonFinite,
+    /// The amount does not fit the amount type.
+    Overflow,
+    /// The string is not an uppercase amount.
+    Invalid(String),
+}
+
+impl fmt::Display for ChineseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChineseError::NonFinite => write!(f, "amount is not a finite number"),
+            ChineseError::Overflow => write!(f, "amount overflow"),
+            ChineseError::Invalid(ref s) => write!(f, "invalid uppercase amount {}", s),
+        }
+    }
+}
+
+impl error::Error for ChineseError {}
+
+const PREFIX: &str = "人民币";
+const DIGITS: [char; 10] = ['零', '壹', '贰', '叁', '肆', '伍', '陆', '柒', '捌', '玖'];
+// Units inside a group of four digits, from 10^3 down to 10^1.
+const UNITS: [(char, u128); 3] = [('仟', 1000), ('佰', 100), ('拾', 10)];
+const WAN: u128 = 10_000;
+const YI: u128 = 100_000_000;
+
+/// Writes an amount in Chinese financial uppercase numerals, rounded half to even to 分.
+///
+/// # Examples
+///
+/// ```
+/// # use accounting::{format_chinese_uppercase, ChineseError};
+/// assert_eq!(format_chinese_uppercase(0).unwrap(), "人民币零元整");
+/// assert_eq!(format_chinese_uppercase(0.5).unwrap(), "人民币伍角");
+/// assert_eq!(format_chinese_uppercase(-20.07).unwrap(), "人民币负贰拾元零柒分");
+/// assert_eq!(format_chinese_uppercase(f64::NAN), Err(ChineseError::NonFinite));
+/// ```
+pub fn format_chinese_uppercase<T: FormatNumber>(value: T) -> Result<String> {
+    let digits = value.to_digits().round(2, RoundingMode::HalfEven);
+    if !digits.is_finite() {
+        return Err(ChineseError::NonFinite);
+    }
+    let (yuan, fen) = digits.to_major_minor(2).ok_or(ChineseError::Overflow)?;
+    let (jiao, fen) = ((fen / 10) as usize, (fen % 10) as usize);
+
+    let mut result = PREFIX.to_string();
+    if digits.is_negative() {
+        result.push('负');
+    }
+    if yuan > 0 {
+        push_integer(&mut result, yuan);
+        result.push('元');
+    }
+    match (jiao, fen) {
+        (0, 0) if yuan == 0 => result.push_str("零元整"),
+        (0, 0) => result.push('整'),
+        _ => {
+            if jiao > 0 {
+                result.push(DIGITS[jiao]);
+                result.push('角');
+            } else if yuan > 0 {
+                result.push('零');
+            }
+            if fen > 0 {
+                result.push(DIGITS[fen]);
+                result.push('分');
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Appends a positive integer, splitting it at `亿` and `万`.
+fn push_integer(result: &mut String, n: u128) {
+    if n >= YI {
+        push_integer(result, n / YI);
+        result.push('亿');
+        push_below(result, n % YI, YI / 10, push_below_yi);
+    } else {
+        push_below_yi(result, n);
+    }
+}
+
+/// Appends a positive integer below 10^8.
+fn push_below_yi(result: &mut String, n: u128) {
+    if n >= WAN {
+        push_group(result, n / WAN);
+        result.push('万');
+        push_below(result, n % WAN, WAN / 10, push_group);
+    } else {
+        push_group(result, n);
+    }
+}
+
+/// Appends the lower part after `亿` or `万`, with a `零` if its first digit is zero.
+fn push_below(result: &mut String, n: u128, first_place: u128, push: fn(&mut String, u128)) {
+    if n == 0 {
+        return;
+    }
+    if n < first_place {
+        result.push('零');
+    }
+    push(result, n);
+}
+
+/// Appends a positive integer below 10^4, with one `零` for skipped places and none at the end.
+fn push_group(result: &mut String, n: u128) {
+    let mut zero = false;
+    let mut started = false;
+    for (unit, value) in UNITS.iter().map(|&(c, v)| (Some(c), v)).chain([(None, 1)]) {
+        let d = (n / value % 10) as usize;
+        if d == 0 {
+            zero = started;
+            continue;
+        }
+        if zero {
+            result.push('零');
+            zero = false;
+        }
+        result.push(DIGITS[d]);
+        if let Some(unit) = unit {
+            result.push(unit);
+        }
+        started = true;
+    }
+}
+
+/// Reads an amount written in Chinese financial uppercase numerals as [Money] in `CNY`.
+///
+/// The `人民币` prefix is optional, `圆` is accepted for `元` and `正` for `整`.
+/// The integer part has to be written like [format_chinese_uppercase] writes it: every run of
+/// skipped places before a digit is marked with one `零`, inside a group of four digits and
+/// after `万` and `亿`. So `壹仟伍元整`, `壹万伍拾元整` and `壹仟零零伍元整` are invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use accounting::{parse_chinese_uppercase, ChineseError, Money};
+/// let money = parse_chinese_uppercase("人民币壹仟贰佰叁拾肆元伍角陆分").unwrap();
+/// assert_eq!(money.minor_units(), 123456);
+/// assert_eq!(parse_chinese_uppercase("壹万亿元整").unwrap().minor_units(), 100_000_000_000_000);
+/// assert!(matches!(parse_chinese_uppercase("壹拾贰"), Err(ChineseError::Invalid(_))));
+/// assert!(matches!(parse_chinese_uppercase("壹万伍元整"), Err(ChineseError::Invalid(_))));
+/// ```
+pub fn parse_chinese_uppercase(s: &str) -> Result<Money> {
+    let invalid = || ChineseError::Invalid(s.to_string());
+    let rest = s.trim();
+    let rest = rest.strip_prefix(PREFIX).unwrap_or(rest);
+    let (negative, rest) = match rest.strip_prefix('负') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+
+    let (yuan, rest) = match rest.find(['元', '圆']) {
+        Some(index) => {
+            let integer = &rest[..index];
+            let yuan = if integer == "零" { 0 } else { parse_integer(integer).ok_or_else(invalid)? };
+            (yuan, &rest[index + '元'.len_utf8()..])
+        }
+        // Without 元 there must be 角 or 分.
+        None if !matches!(rest, "" | "整" | "正") => (0, rest),
+        None => return Err(invalid()),
+    };
+    let fen = parse_fraction(rest).ok_or_else(invalid)?;
+
+    let minor = yuan.checked_mul(100).and_then(|n| n.checked_add(fen)).ok_or(ChineseError::Overflow)?;
+    let minor = i128::try_from(minor).map_err(|_| ChineseError::Overflow)?;
+    // `CNY` is in the registry, so only the range of the amount can fail.
+    Money::from_minor(if negative { -minor } else { minor }, "CNY").map_err(|_| ChineseError::Overflow)
+}
+
+/// Reads the part after `元`: nothing, `整`, or 角 and 分 with an optional `零` before them.
+fn parse_fraction(s: &str) -> Option<u128> {
+    if s.is_empty() || s == "整" || s == "正" {
+        return Some(0);
+    }
+    // `整` may also follow 角.
+    let s = s.strip_suffix(['整', '正']).filter(|s| s.ends_with('角')).unwrap_or(s);
+    let mut fen = 0;
+    let mut chars = s.chars().peekable();
+    let mut allowed = ['角', '分'].as_slice();
+    chars.next_if_eq(&'零');
+    while let Some(c) = chars.next() {
+        let d = digit(c)?;
+        let unit = chars.next()?;
+        let position = allowed.iter().position(|&u| u == unit)?;
+        fen += d as u128 * if unit == '角' { 10 } else { 1 };
+        allowed = &allowed[position + 1..];
+        if unit == '角' {
+            chars.next_if_eq(&'零');
+        }
+    }
+    // Skipping 角 with `零` is only allowed before 分.
+    (fen % 10 != 0 || !s.ends_with('零')).then_some(fen)
+}
+
+/// Reads a positive integer in the form written by [push_integer], so `零` marks exactly the
+/// skipped places. Saturates at `u128::MAX`, which is reported as overflow later.
+fn parse_integer(s: &str) -> Option<u128> {
+    let n = parse_places(s)?;
+    let mut written = String::new();
+    push_integer(&mut written, n);
+    (n == u128::MAX || written == s).then_some(n)
+}
+
+/// Reads the value of a positive integer, splitting it at the last `亿` and then at `万`.
+fn parse_places(s: &str) -> Option<u128> {
+    if let Some(index) = s.rfind('亿') {
+        let high = parse_places(&s[..index])?;
+        let low = parse_below(&s[index + '亿'.len_utf8()..], parse_below_yi)?;
+        return Some(high.saturating_mul(YI).saturating_add(low));
+    }
+    parse_below_yi(s).filter(|&n| n > 0)
+}
+
+fn parse_below_yi(s: &str) -> Option<u128> {
+    match s.find('万') {
+        Some(index) => {
+            let high = parse_group(&s[..index]).filter(|&n| n > 0)?;
+            let low = parse_below(&s[index + '万'.len_utf8()..], parse_group)?;
+            Some(high * WAN + low)
+        }
+        None => parse_group(s),
+    }
+}
+
+/// Reads the lower part after `亿` or `万`, which may be empty or start with `零`.
+fn parse_below(s: &str, parse: fn(&str) -> Option<u128>) -> Option<u128> {
+    let s = s.strip_prefix('零').unwrap_or(s);
+    if s.is_empty() {
+        Some(0)
+    } else {
+        parse(s)
+    }
+}
+
+/// Reads an integer below 10^4, like `壹仟零伍`, with the units in decreasing order.
+fn parse_group(s: &str) -> Option<u128> {
+    let mut n = 0;
+    let mut units = UNITS.as_slice();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        let d = digit(c)? as u128;
+        if d == 0 {
+            continue;
+        }
+        match chars.next() {
+            Some(unit) => {
+                let position = units.iter().position(|&(u, _)| u == unit)?;
+                n += d * units[position].1;
+                units = &units[position + 1..];
+            }
+            None => return Some(n + d),
+        }
+    }
+    (!s.is_empty()).then_some(n)
+}
+
+fn digit(c: char) -> Option<usize> {
+    DIGITS.iter().position(|&d| d == c)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_test() {
+        let cases = [
+            (1234.56, "壹仟贰佰叁拾肆元伍角陆分"),
+            (1.0, "壹元整"),
+            (10.0, "壹拾元整"),
+            (10.5, "壹拾元伍角"),
+            (1.05, "壹元零伍分"),
+            (0.05, "伍分"),
+            (1005.0, "壹仟零伍元整"),
+            (1050.0, "壹仟零伍拾元整"),
+            (10001.0, "壹万零壹元整"),
+            (100010.0, "壹拾万零壹拾元整"),
+            (10010000.0, "壹仟零壹万元整"),
+            (100000000.0, "壹亿元整"),
+            (100500000.0, "壹亿零伍拾万元整"),
+            (150000000.0, "壹亿伍仟万元整"),
+            (1000000001.0, "壹拾亿零壹元整"),
+            (1e12, "壹万亿元整"),
+            (1e16, "壹亿亿元整"),
+        ];
+        for (value, expected) in cases.iter() {
+            assert_eq!(format_chinese_uppercase(*value).unwrap(), format!("人民币{}", expected));
+            let money = parse_chinese_uppercase(expected).unwrap();
+            assert_eq!(money.minor_units(), (*value * 100.0_f64).round() as i128, "{}", expected);
+        }
+        assert_eq!(format_chinese_uppercase(-0.001).unwrap(), "人民币零元整");
+        assert_eq!(format_chinese_uppercase(1e40), Err(ChineseError::Overflow));
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(parse_chinese_uppercase("零元整").unwrap().minor_units(), 0);
+        assert_eq!(parse_chinese_uppercase("壹圆正").unwrap().minor_units(), 100);
+        assert_eq!(parse_chinese_uppercase("壹元").unwrap().minor_units(), 100);
+        assert_eq!(parse_chinese_uppercase(" 人民币伍角整 ").unwrap().minor_units(), 50);
+        assert_eq!(parse_chinese_uppercase("负壹元零伍分").unwrap().minor_units(), -105);
+        assert_eq!(parse_chinese_uppercase("壹万零壹拾元").unwrap().minor_units(), 1001000);
+        assert_eq!(parse_chinese_uppercase("壹拾亿零壹元整").unwrap().minor_units(), 100000000100);
+        assert_eq!(parse_chinese_uppercase("壹拾伍元整").unwrap().minor_units(), 1500);
+        assert_eq!(parse_chinese_uppercase("壹万零伍拾伍元").unwrap().minor_units(), 1005500);
+        assert_eq!(parse_chinese_uppercase("壹亿零伍万元").unwrap().minor_units(), 10005000000);
+        assert_eq!(parse_chinese_uppercase("壹仟零壹万零壹拾元").unwrap().minor_units(), 1001001000);
+
+        let invalid = [
+            "", "人民币", "整", "壹", "拾元", "壹壹元", "壹拾仟元", "元零", "壹元伍", "壹元伍分伍角", "壹元伍角零",
+            // Skipped places without `零`, inside a group and after `万` or `亿`.
+            "壹仟伍元整", "壹佰伍元", "壹仟伍拾元", "壹万伍元整", "壹万伍拾伍元整", "壹亿伍元整", "壹亿伍万元整",
+            // `零` without skipped places, or more than one for a run of them.
+            "壹拾零伍元", "壹万零伍仟元", "壹仟零零伍元", "壹仟零元", "零壹元", "壹万零元",
+        ];
+        for invalid in invalid {
+            assert_eq!(parse_chinese_uppercase(invalid), Err(ChineseError::Invalid(invalid.to_string())), "{}", invalid);
+        }
+        assert_eq!(parse_chinese_uppercase("壹亿亿亿亿亿元"), Err(ChineseError::Overflow));
+
+        // Everything written is read back.
+        for yuan in (1..=100_000_000_000i64).step_by(9_999_991).chain(1..=10_100).chain([100_000_001, 1_000_010_000]) {
+            let s = format_chinese_uppercase(yuan).unwrap();
+            assert_eq!(parse_chinese_uppercase(&s).unwrap().minor_units(), yuan as i128 * 100, "{}", s);
+        }
+    }
+}
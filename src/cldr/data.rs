@@ -0,0 +1,239 @@
+//! Snapshot of CLDR number symbols, currency and percent patterns for common locales.
+//!
+//! Only values that differ from the parent locale are listed, missing values are
+//! inherited along the fallback chain down to `root`.
+
+use super::LocaleData;
+
+const NBSP: &str = "\u{a0}";
+const NNBSP: &str = "\u{202f}";
+const MINUS: &str = "\u{2212}";
+
+const fn locale(id: &'static str, parent: Option<&'static str>) -> LocaleData {
+    LocaleData {
+        id,
+        parent,
+        decimal: None,
+        group: None,
+        minus: None,
+        pattern: None,
+        percent: None,
+        min_grouping_digits: None,
+        symbols: &[],
+    }
+}
+
+// `root` first, then sorted by id.
+pub(super) static LOCALES: [LocaleData; 38] = [
+    LocaleData {
+        decimal: Some("."),
+        group: Some(","),
+        minus: Some("-"),
+        pattern: Some("¤\u{a0}#,##0.00"),
+        percent: Some("#,##0%"),
+        min_grouping_digits: Some(1),
+        symbols: &[
+            ("AUD", "A$"), ("BRL", "R$"), ("CAD", "CA$"), ("CNY", "CN¥"), ("EUR", "€"),
+            ("GBP", "£"), ("HKD", "HK$"), ("ILS", "₪"), ("INR", "₹"), ("JPY", "JP¥"),
+            ("KRW", "₩"), ("MXN", "MX$"), ("NZD", "NZ$"), ("PHP", "₱"), ("TWD", "NT$"),
+            ("USD", "US$"), ("VND", "₫"), ("XAF", "FCFA"), ("XCD", "EC$"), ("XOF", "F\u{202f}CFA"),
+        ],
+        ..locale("root", None)
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        ..locale("da", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        ..locale("de", Some("root"))
+    },
+    LocaleData {
+        group: Some(NBSP),
+        pattern: Some("¤\u{a0}#,##0.00"),
+        ..locale("de-AT", Some("de"))
+    },
+    LocaleData {
+        decimal: Some("."),
+        group: Some("’"),
+        pattern: Some("¤\u{a0}#,##0.00;¤-#,##0.00"),
+        percent: Some("#,##0%"),
+        ..locale("de-CH", Some("de"))
+    },
+    LocaleData {
+        pattern: Some("¤#,##0.00"),
+        symbols: &[("JPY", "¥"), ("USD", "$")],
+        ..locale("en", Some("root"))
+    },
+    LocaleData {
+        symbols: &[("USD", "US$")],
+        ..locale("en-001", Some("en"))
+    },
+    LocaleData {
+        symbols: &[("AUD", "$"), ("USD", "USD")],
+        ..locale("en-AU", Some("en-001"))
+    },
+    LocaleData {
+        symbols: &[("CAD", "$"), ("USD", "US$")],
+        ..locale("en-CA", Some("en-001"))
+    },
+    locale("en-GB", Some("en-001")),
+    locale("en-IE", Some("en-001")),
+    LocaleData {
+        pattern: Some("¤#,##,##0.00"),
+        percent: Some("#,##,##0%"),
+        ..locale("en-IN", Some("en-001"))
+    },
+    locale("en-US", Some("en")),
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        min_grouping_digits: Some(2),
+        symbols: &[("USD", "US$")],
+        ..locale("es", Some("root"))
+    },
+    LocaleData {
+        decimal: Some("."),
+        group: Some(","),
+        pattern: Some("¤#,##0.00"),
+        min_grouping_digits: Some(1),
+        ..locale("es-419", Some("es"))
+    },
+    LocaleData {
+        symbols: &[("MXN", "$")],
+        ..locale("es-MX", Some("es-419"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some(NBSP),
+        minus: Some(MINUS),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        ..locale("fi", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some(NNBSP),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{202f}%"),
+        symbols: &[("AUD", "$AU"), ("CAD", "$CA"), ("GBP", "£GB"), ("HKD", "$HK"), ("USD", "$US")],
+        ..locale("fr", Some("root"))
+    },
+    LocaleData {
+        group: Some(NBSP),
+        symbols: &[("CAD", "$"), ("USD", "$\u{a0}US")],
+        percent: Some("#,##0\u{a0}%"),
+        ..locale("fr-CA", Some("fr"))
+    },
+    LocaleData {
+        percent: Some("#,##0%"),
+        ..locale("fr-CH", Some("fr"))
+    },
+    LocaleData {
+        pattern: Some("¤#,##,##0.00"),
+        percent: Some("#,##,##0%"),
+        ..locale("hi", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        ..locale("it", Some("root"))
+    },
+    LocaleData {
+        decimal: Some("."),
+        group: Some("’"),
+        pattern: Some("¤\u{a0}#,##0.00;¤-#,##0.00"),
+        ..locale("it-CH", Some("it"))
+    },
+    LocaleData {
+        pattern: Some("¤#,##0.00"),
+        symbols: &[("CNY", "元"), ("JPY", "￥")],
+        ..locale("ja", Some("root"))
+    },
+    LocaleData {
+        pattern: Some("¤#,##0.00"),
+        ..locale("ko", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some(NBSP),
+        minus: Some(MINUS),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        symbols: &[("NOK", "kr")],
+        ..locale("nb", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("¤\u{a0}#,##0.00;¤\u{a0}-#,##0.00"),
+        ..locale("nl", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some(NBSP),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        min_grouping_digits: Some(2),
+        symbols: &[("PLN", "zł")],
+        ..locale("pl", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("¤\u{a0}#,##0.00"),
+        ..locale("pt", Some("root"))
+    },
+    LocaleData {
+        group: Some(NBSP),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        min_grouping_digits: Some(2),
+        ..locale("pt-PT", Some("pt"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some(NBSP),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        symbols: &[("RUB", "₽")],
+        ..locale("ru", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some(NBSP),
+        minus: Some(MINUS),
+        pattern: Some("#,##0.00\u{a0}¤"),
+        percent: Some("#,##0\u{a0}%"),
+        symbols: &[("SEK", "kr")],
+        ..locale("sv", Some("root"))
+    },
+    LocaleData {
+        decimal: Some(","),
+        group: Some("."),
+        pattern: Some("¤#,##0.00"),
+        percent: Some("%#,##0"),
+        symbols: &[("TRY", "₺")],
+        ..locale("tr", Some("root"))
+    },
+    LocaleData {
+        pattern: Some("¤#,##0.00"),
+        symbols: &[("CNY", "¥")],
+        ..locale("zh", Some("root"))
+    },
+    locale("zh-CN", Some("zh")),
+    locale("zh-HK", Some("zh-Hant")),
+    LocaleData {
+        pattern: Some("¤#,##0.00"),
+        symbols: &[("HKD", "HK$"), ("TWD", "$")],
+        ..locale("zh-Hant", Some("root"))
+    },
+    locale("zh-TW", Some("zh-Hant")),
+];
@@ -0,0 +1,298 @@
+//! Locale-aware formatting backed by an embedded snapshot of CLDR data.
+//!
+//! The same currency is written differently per locale, [Accounting::for_locale] picks the
+//! separators, currency pattern and localized symbol of a locale. Locales missing from the
+//! snapshot fall back along the CLDR chain, e.g. `de-LU → de → root`.
+//! [Percent::for_locale] picks the spacing and position of the percent sign.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Accounting;
+//! let ac = Accounting::for_locale("de-DE", "EUR").unwrap();
+//! assert_eq!(ac.format_money(1234.56), "1.234,56\u{a0}€");
+//!
+//! let ac = Accounting::for_locale("fr-FR", "EUR").unwrap();
+//! assert_eq!(ac.format_money(1234.56), "1\u{202f}234,56\u{a0}€");
+//!
+//! let ac = Accounting::for_locale("en-IE", "EUR").unwrap();
+//! assert_eq!(ac.format_money(1234.56), "€1,234.56");
+//! ```
+
+mod data;
+
+use std::error;
+use std::fmt;
+use crate::currency::{Currency, CurrencyError};
+use crate::pattern::Pattern;
+use crate::{Accounting, Percent, PercentUnit};
+use data::LOCALES;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocaleError {
+    /// Neither the locale nor its language is in the CLDR snapshot.
+    UnknownLocale(String),
+    /// The currency code is not in the [Currency] registry.
+    Currency(CurrencyError),
+}
+
+impl fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LocaleError::UnknownLocale(ref id) => write!(f, "unknown locale {}", id),
+            LocaleError::Currency(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for LocaleError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            LocaleError::UnknownLocale(_) => None,
+            LocaleError::Currency(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<CurrencyError> for LocaleError {
+    fn from(err: CurrencyError) -> LocaleError {
+        LocaleError::Currency(err)
+    }
+}
+
+/// Number symbols, currency and percent patterns of one locale, `None` means inherited.
+struct LocaleData {
+    id: &'static str,
+    parent: Option<&'static str>,
+    decimal: Option<&'static str>,
+    group: Option<&'static str>,
+    minus: Option<&'static str>,
+    pattern: Option<&'static str>,
+    percent: Option<&'static str>,
+    min_grouping_digits: Option<usize>,
+    symbols: &'static [(&'static str, &'static str)],
+}
+
+/// Returns the ids of all locales in the snapshot.
+pub fn available_locales() -> impl Iterator<Item = &'static str> {
+    LOCALES.iter().map(|l| l.id)
+}
+
+/// Normalizes `de_at` or `DE-AT` to the CLDR form `de-AT`, and `zh_hant` to `zh-Hant`.
+fn normalize(id: &str) -> String {
+    id.split(['-', '_'])
+        .enumerate()
+        .map(|(i, part)| match part.len() {
+            _ if i == 0 => part.to_lowercase(),
+            4 => part[..1].to_uppercase() + &part[1..].to_lowercase(),
+            _ => part.to_uppercase(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Returns the locale data along the fallback chain, from the locale itself to `root`.
+fn fallback_chain(id: &str) -> Result<Vec<&'static LocaleData>, LocaleError> {
+    let find = |id: &str| LOCALES.iter().find(|l| l.id == id);
+    let normalized = normalize(id);
+
+    let mut chain = Vec::new();
+    let mut current = normalized.clone();
+    loop {
+        let next = match find(&current) {
+            Some(data) => {
+                chain.push(data);
+                match data.parent {
+                    Some(parent) => parent.to_string(),
+                    None => break,
+                }
+            }
+            None => match current.rfind('-') {
+                Some(index) => current[..index].to_string(),
+                None => "root".to_string(),
+            },
+        };
+        current = next;
+    }
+
+    if chain.len() == 1 && normalized != "root" {
+        return Err(LocaleError::UnknownLocale(id.to_string()));
+    }
+    Ok(chain)
+}
+
+impl Accounting {
+    /// Create Accounting for a currency as written in a locale, using the embedded CLDR snapshot.
+    ///
+    /// The precision comes from the [Currency] registry, the separators, grouping, pattern and
+    /// symbol from the locale or its fallbacks. Currencies without a localized symbol use their code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, LocaleError};
+    /// let ac = Accounting::for_locale("de-AT", "EUR").unwrap();
+    /// assert_eq!(ac.format_money(-1234.56), "-€\u{a0}1\u{a0}234,56");
+    ///
+    /// let ac = Accounting::for_locale("hi", "INR").unwrap();
+    /// assert_eq!(ac.format_money(1234567), "₹12,34,567.00");
+    ///
+    /// assert!(matches!(Accounting::for_locale("xx", "EUR"), Err(LocaleError::UnknownLocale(_))));
+    /// ```
+    pub fn for_locale(locale: &str, currency: &str) -> Result<Self, LocaleError> {
+        let currency = Currency::try_from_code(currency)?;
+        let chain = fallback_chain(locale)?;
+        // Every field is set on root, which ends the chain.
+        let resolve = |field: fn(&LocaleData) -> Option<&'static str>| {
+            chain.iter().find_map(|l| field(l)).unwrap()
+        };
+
+        let minus = resolve(|l| l.minus);
+        let pattern = resolve(|l| l.pattern);
+        let symbol = chain
+            .iter()
+            .find_map(|l| l.symbols.iter().find(|(code, _)| *code == currency.code()))
+            .map_or(currency.code(), |(_, symbol)| symbol);
+
+        // Patterns of the snapshot are valid.
+        let pattern = Pattern::parse(pattern, minus).unwrap();
+        let mut grouping = pattern.grouping;
+        grouping.min_grouping_digits = chain.iter().find_map(|l| l.min_grouping_digits).unwrap();
+
+        let mut ac = Accounting::new(
+            symbol,
+            currency.exponent() as usize,
+            resolve(|l| l.group),
+            resolve(|l| l.decimal),
+            &pattern.positive,
+            &pattern.negative,
+            &pattern.positive,
+        );
+        ac.set_grouping(grouping);
+        ac.set_code(currency.code());
+        ac.set_name(currency.name());
+        Ok(ac)
+    }
+}
+
+impl Percent {
+    /// Create Percent for a unit with the spacing and position of the percent sign in a locale,
+    /// using the embedded CLDR snapshot.
+    ///
+    /// The spacing of the locale also applies to per mille and basis points, basis points keep
+    /// their space in locales without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Percent, PercentUnit};
+    /// let ac = Accounting::for_locale("fr-FR", "EUR").unwrap();
+    /// let percent = Percent::for_locale("fr-FR", PercentUnit::Percent).unwrap();
+    /// assert_eq!(ac.format_percent(0.0525, &percent), "5,25\u{202f}%");
+    ///
+    /// let ac = Accounting::for_locale("tr", "TRY").unwrap();
+    /// let percent = Percent::for_locale("tr", PercentUnit::Percent).unwrap();
+    /// assert_eq!(ac.format_percent(-0.0525, &percent), "-%5,25");
+    /// ```
+    pub fn for_locale(locale: &str, unit: PercentUnit) -> Result<Self, LocaleError> {
+        let chain = fallback_chain(locale)?;
+        // Every field is set on root, which ends the chain.
+        let pattern = chain.iter().find_map(|l| l.percent).unwrap();
+        let number = |c: char| matches!(c, '#' | '0' | ',' | '.');
+
+        let mut percent = Percent::new(unit);
+        let space = match pattern.strip_prefix('%') {
+            Some(rest) => {
+                percent.set_symbol_first(true);
+                rest.split(number).next().unwrap()
+            }
+            None => pattern.trim_start_matches(number).trim_end_matches('%'),
+        };
+        if !space.is_empty() {
+            percent.set_space(space);
+        }
+        Ok(percent)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(locale: &str, currency: &str, value: f64) -> String {
+        Accounting::for_locale(locale, currency).unwrap().format_money(value)
+    }
+
+    #[test]
+    fn snapshot_test() {
+        assert_eq!(LOCALES[0].id, "root");
+        assert!(LOCALES[1..].iter().all(|l| l.parent.is_some()));
+        for id in available_locales() {
+            assert!(fallback_chain(id).unwrap().last().unwrap().id == "root", "{}", id);
+        }
+    }
+
+    #[test]
+    fn fallback_test() {
+        let ids = |id| fallback_chain(id).unwrap().iter().map(|l| l.id).collect::<Vec<_>>();
+        assert_eq!(ids("de-AT"), vec!["de-AT", "de", "root"]);
+        assert_eq!(ids("de_lu"), vec!["de", "root"]);
+        assert_eq!(ids("en-IE"), vec!["en-IE", "en-001", "en", "root"]);
+        assert_eq!(ids("zh-hant-tw"), vec!["zh-Hant", "root"]);
+        assert_eq!(ids("root"), vec!["root"]);
+        assert_eq!(fallback_chain("xx-YY").err(), Some(LocaleError::UnknownLocale("xx-YY".to_string())));
+    }
+
+    #[test]
+    fn for_locale_test() {
+        assert_eq!(format("de-DE", "EUR", -1234.56), "-1.234,56\u{a0}€");
+        assert_eq!(format("fr-CA", "CAD", 1234.56), "1\u{a0}234,56\u{a0}$");
+        assert_eq!(format("fr-FR", "USD", 1234.56), "1\u{202f}234,56\u{a0}$US");
+        assert_eq!(format("en-US", "USD", -1234.56), "-$1,234.56");
+        assert_eq!(format("en-GB", "USD", 1234.56), "US$1,234.56");
+        assert_eq!(format("en-US", "JPY", 1234.56), "¥1,235");
+        assert_eq!(format("ja-JP", "JPY", 1234.56), "￥1,235");
+        assert_eq!(format("de-CH", "CHF", -1234.5), "CHF-1’234.50");
+        assert_eq!(format("nl-NL", "EUR", -1234.5), "€\u{a0}-1.234,50");
+        assert_eq!(format("sv-SE", "SEK", -5.0), "\u{2212}5,00\u{a0}kr");
+        assert_eq!(format("es-ES", "EUR", 1234.0), "1234,00\u{a0}€");
+        assert_eq!(format("es-ES", "EUR", 12345.0), "12.345,00\u{a0}€");
+        assert_eq!(format("es-MX", "MXN", 1234.0), "$1,234.00");
+        assert_eq!(format("root", "USD", 1234.0), "US$\u{a0}1,234.00");
+        assert_eq!(format("pt-BR", "BRL", 1234.0), "R$\u{a0}1.234,00");
+        assert_eq!(format("en-IN", "INR", 12345678.9), "₹1,23,45,678.90");
+        assert_eq!(format("de", "PLN", 1.0), "1,00\u{a0}PLN");
+    }
+
+    #[test]
+    fn percent_for_locale_test() {
+        let percent = |locale: &str, unit| {
+            let ac = Accounting::for_locale(locale, "EUR").unwrap();
+            ac.format_percent(-0.0525, &Percent::for_locale(locale, unit).unwrap())
+        };
+        assert_eq!(percent("en-US", PercentUnit::Percent), "-5.25%");
+        assert_eq!(percent("de-AT", PercentUnit::Percent), "-5,25\u{a0}%");
+        assert_eq!(percent("de-CH", PercentUnit::Percent), "-5.25%");
+        assert_eq!(percent("fr-CA", PercentUnit::PerMille), "-52,5\u{a0}‰");
+        assert_eq!(percent("fr-CH", PercentUnit::Percent), "-5,25%");
+        assert_eq!(percent("sv", PercentUnit::Percent), "\u{2212}5,25\u{a0}%");
+        assert_eq!(percent("tr", PercentUnit::PerMille), "-‰52,5");
+        assert_eq!(percent("ja", PercentUnit::BasisPoint), "-525 bp");
+        assert_eq!(percent("ru", PercentUnit::BasisPoint), "-525\u{a0}bp");
+        assert!(LOCALES.iter().filter_map(|l| l.percent).all(|p| p.contains('%')));
+        assert_eq!(Percent::for_locale("qq", PercentUnit::Percent).err(), Some(LocaleError::UnknownLocale("qq".to_string())));
+    }
+
+    #[test]
+    fn for_locale_error_test() {
+        assert_eq!(
+            Accounting::for_locale("de-DE", "XYZ").err(),
+            Some(LocaleError::Currency(CurrencyError::UnknownCode("XYZ".to_string())))
+        );
+        assert_eq!(
+            Accounting::for_locale("qq", "EUR").err(),
+            Some(LocaleError::UnknownLocale("qq".to_string()))
+        );
+    }
+}
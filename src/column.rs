@@ -0,0 +1,147 @@
+//! Column formatting, padding money values so they line up in a table.
+
+use unicode_width::UnicodeWidthStr;
+use crate::{Accounting, FormatNumber};
+
+/// The parts of a formatted value that are aligned separately.
+#[derive(Default)]
+struct Cell {
+    // The text up to the last symbol, code or name before the value.
+    lead: String,
+    // The text between the lead and the value, like a sign, kept next to the digits.
+    before: String,
+    integer: String,
+    fraction: String,
+    after: String,
+    // False if the format string has no `{v}`, like `{s} --`.
+    has_value: bool,
+}
+
+impl Accounting {
+    /// Formats a list of values as a column, padding every value with spaces to the same width.
+    ///
+    /// Symbols before the value line up on the left, with signs and parentheses in front of them
+    /// padded to the same width. A sign written right before the value, like in `-{v} €`, stays
+    /// next to the digits. The decimal separators line up vertically and everything after the value,
+    /// like a closing parenthesis, is aligned too.
+    /// Widths are measured in Unicode display width, so wide symbols line up in terminals.
+    /// Values formatted without `{v}`, like a zero format `{s} --`, are aligned on the left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::Accounting;
+    /// let mut ac = Accounting::new_from("$", 2);
+    /// ac.set_format_negative("({s}{v})").unwrap();
+    /// let column = ac.format_column(&[123.5, -12345.678, 0.0]);
+    /// assert_eq!(column, [
+    ///     " $   123.50 ",
+    ///     "($12,345.68)",
+    ///     " $     0.00 ",
+    /// ]);
+    /// ```
+    pub fn format_column<T: FormatNumber>(&self, values: &[T]) -> Vec<String> {
+        let cells: Vec<Cell> = values
+            .iter()
+            .map(|value| {
+                let rounded = self.round(value);
+                let abs = rounded.digits.abs();
+                let format = self.value_format(&rounded);
+                let placeholders = self.placeholders(&rounded, &abs, &format);
+                let template = self.template(&rounded.digits);
+
+                // Writing to a `String` never fails.
+                let mut cell = Cell::default();
+                cell.has_value = template
+                    .write_around_value(&mut cell.lead, &mut cell.before, &mut cell.after, &placeholders)
+                    .unwrap();
+                if cell.has_value {
+                    cell.integer = std::mem::take(&mut cell.before);
+                    abs.write_integer(&mut cell.integer, &format).unwrap();
+                    abs.write_fraction(&mut cell.fraction, &format).unwrap();
+                    cell.fraction.push_str(placeholders.suffix);
+                }
+                cell
+            })
+            .collect();
+
+        let max_width = |part: fn(&Cell) -> &str| {
+            cells.iter().filter(|c| c.has_value).map(|c| part(c).width()).max().unwrap_or(0)
+        };
+        let lead = max_width(|c| &c.lead);
+        let mut integer = max_width(|c| &c.integer);
+        let fraction = max_width(|c| &c.fraction);
+        let after = max_width(|c| &c.after);
+
+        // Values without `{v}` may be wider than the others, the extra space goes left of the numbers.
+        let text = cells.iter().filter(|c| !c.has_value).map(|c| c.lead.width()).max().unwrap_or(0);
+        let total = (lead + integer + fraction + after).max(text);
+        integer += total - (lead + integer + fraction + after);
+
+        cells
+            .iter()
+            .map(|cell| {
+                let mut result = String::new();
+                if cell.has_value {
+                    pad_left(&mut result, &cell.lead, lead);
+                    pad_left(&mut result, &cell.integer, integer);
+                    pad_right(&mut result, &cell.fraction, fraction);
+                    pad_right(&mut result, &cell.after, after);
+                } else {
+                    pad_right(&mut result, &cell.lead, total);
+                }
+                result
+            })
+            .collect()
+    }
+}
+
+fn pad_left(result: &mut String, s: &str, width: usize) {
+    result.extend(std::iter::repeat_n(' ', width.saturating_sub(s.width())));
+    result.push_str(s);
+}
+
+fn pad_right(result: &mut String, s: &str, width: usize) {
+    result.push_str(s);
+    result.extend(std::iter::repeat_n(' ', width.saturating_sub(s.width())));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use unicode_width::UnicodeWidthStr;
+    use crate::Accounting;
+
+    #[test]
+    fn column_test() {
+        let ac = Accounting::new_from("€", 2);
+        assert_eq!(ac.format_column(&[1, -1000, 10]), [" €    1.00", "-€1,000.00", " €   10.00"]);
+        assert!(ac.format_column::<i32>(&[]).is_empty());
+
+        let mut ac = Accounting::new_from("$", 4);
+        ac.set_min_fraction_digits(0);
+        assert_eq!(ac.format_column(&[12.0, 0.0045, -1.5]), [" $12     ", " $ 0.0045", "-$ 1.5   "]);
+    }
+
+    #[test]
+    fn column_suffix_test() {
+        let mut ac = Accounting::new_from("kr", 2);
+        ac.set_format("{v} {s}").unwrap();
+        ac.set_format_negative("({v} {s})").unwrap();
+        ac.set_format_zero("--").unwrap();
+        assert_eq!(ac.format_column(&[5.5, -1234.0, 0.0]), [
+            "     5.50 kr ",
+            "(1,234.00 kr)",
+            "--           ",
+        ]);
+    }
+
+    #[test]
+    fn column_wide_test() {
+        let mut ac = Accounting::new_from("￥", 0);
+        ac.set_format_zero("{s} 零").unwrap();
+        let column = ac.format_column(&[8, 123456, 0]);
+        assert_eq!(column, ["￥      8", "￥123,456", "￥ 零    "]);
+        assert!(column.iter().all(|s| s.width() == 9));
+    }
+}
@@ -0,0 +1,219 @@
+//! Compact notation for dashboards, like `$1.2K`, `€3.45M` or `-$12B`.
+//!
+//! Set with [Accounting::set_compact](crate::Accounting::set_compact), compact values still go
+//! through the format strings of the Accounting, so negatives render with `format_negative`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{Accounting, Compact};
+//! let mut ac = Accounting::new_from("€", 2);
+//! let mut compact = Compact::short();
+//! compact.set_significant_digits(3);
+//! ac.set_compact(Some(compact));
+//! assert_eq!(ac.format_money(3_450_000), "€3.45M");
+//!
+//! ac.set_compact(Some(Compact::long()));
+//! ac.set_format_negative("({s}{v})").unwrap();
+//! assert_eq!(ac.format_money(-1_200_000), "(€1.2 million)");
+//! ```
+
+use crate::format_number::{Digits, RoundingMode};
+#[cfg(feature = "serde")]
+use crate::config::ConfigError;
+
+/// Settings of the compact notation.
+///
+/// | Field | Description | Default |
+/// | ------------------- | ------------- | ------------- |
+/// | scales              | powers of ten and their suffixes | `K`, `M`, `B`, `T` |
+/// | threshold           | smallest power of ten that is compacted | 3 |
+/// | significant_digits  | significant digits kept, at least the integer digits are kept | 2 |
+///
+/// With feature `serde`, deserializing sorts the scales like [Compact::new] and rejects
+/// `significant_digits` of 0 with `ConfigError::SignificantDigits`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CompactConfig", into = "CompactConfig"))]
+pub struct Compact {
+    scales: Vec<(u32, String)>,
+    threshold: u32,
+    significant_digits: usize,
+}
+
+impl Default for Compact {
+    /// Returns the short suffixes.
+    fn default() -> Self {
+        Self::short()
+    }
+}
+
+impl Compact {
+    /// Create Compact from powers of ten and their suffixes, like `(3, "K")`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Compact};
+    /// let mut ac = Accounting::new_from("₹", 2);
+    /// ac.set_compact(Some(Compact::new(&[(5, " lakh"), (7, " crore")])));
+    /// assert_eq!(ac.format_money(1_250_000), "₹12 lakh");
+    /// assert_eq!(ac.format_money(45_000_000), "₹4.5 crore");
+    /// ```
+    pub fn new(scales: &[(u32, &str)]) -> Self {
+        let mut scales: Vec<(u32, String)> = scales.iter().map(|&(e, s)| (e, s.to_string())).collect();
+        scales.sort_by_key(|&(e, _)| e);
+        let threshold = scales.first().map_or(0, |&(e, _)| e);
+        Compact { scales, threshold, significant_digits: 2 }
+    }
+
+    /// `K`, `M`, `B` and `T` for thousands, millions, billions and trillions.
+    pub fn short() -> Self {
+        Self::new(&[(3, "K"), (6, "M"), (9, "B"), (12, "T")])
+    }
+
+    /// ` thousand`, ` million`, ` billion` and ` trillion`.
+    pub fn long() -> Self {
+        Self::new(&[(3, " thousand"), (6, " million"), (9, " billion"), (12, " trillion")])
+    }
+
+    /// Sets the smallest power of ten that is compacted, smaller values are formatted in full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Compact};
+    /// let mut ac = Accounting::new_from("$", 0);
+    /// let mut compact = Compact::short();
+    /// compact.set_threshold(4);
+    /// ac.set_compact(Some(compact));
+    /// assert_eq!(ac.format_money(9999), "$9,999");
+    /// assert_eq!(ac.format_money(10000), "$10K");
+    /// ```
+    pub fn set_threshold(&mut self, exponent: u32) {
+        self.threshold = exponent;
+    }
+
+    /// Sets the number of significant digits, integer digits of the scaled value are always kept.
+    pub fn set_significant_digits(&mut self, digits: usize) {
+        self.significant_digits = digits.max(1);
+    }
+
+    /// Scales `digits` down to the largest scale not above them and rounds them to the significant
+    /// digits. Returns `None` if the value is not compacted.
+    pub(crate) fn apply(&self, digits: &Digits, rounding: RoundingMode) -> Option<(Digits, &str)> {
+        let magnitude = digits.magnitude()?;
+        if magnitude < self.threshold as i32 {
+            return None;
+        }
+        let (exponent, suffix) = self.scales.iter().rev().find(|&&(e, _)| e as i32 <= magnitude)?;
+        let exponent = *exponent as i32;
+
+        let integer_digits = magnitude - exponent + 1;
+        let precision = (self.significant_digits as i32 - integer_digits).max(0) as usize;
+        let rounded = digits.clone().shift(-exponent).round(precision, rounding);
+        if rounded.magnitude() > Some(magnitude - exponent) {
+            // Rounding carried into a new digit, like 999.96K into 1000K, which may need the next scale.
+            return self.apply(&rounded.shift(exponent), rounding);
+        }
+        Some((rounded, suffix))
+    }
+}
+
+/// The serialized form of [Compact].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompactConfig {
+    scales: Vec<(u32, String)>,
+    threshold: u32,
+    significant_digits: usize,
+}
+
+#[cfg(feature = "serde")]
+impl From<Compact> for CompactConfig {
+    fn from(compact: Compact) -> Self {
+        CompactConfig {
+            scales: compact.scales,
+            threshold: compact.threshold,
+            significant_digits: compact.significant_digits,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CompactConfig> for Compact {
+    type Error = ConfigError;
+
+    fn try_from(mut config: CompactConfig) -> Result<Self, ConfigError> {
+        if config.significant_digits == 0 {
+            return Err(ConfigError::SignificantDigits);
+        }
+        config.scales.sort_by_key(|&(e, _)| e);
+        Ok(Compact {
+            scales: config.scales,
+            threshold: config.threshold,
+            significant_digits: config.significant_digits,
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Accounting, Compact, RoundingMode};
+
+    fn format(compact: Compact, value: f64) -> String {
+        let mut ac = Accounting::new_from("$", 2);
+        ac.set_compact(Some(compact));
+        ac.format_money(value)
+    }
+
+    #[test]
+    fn short_test() {
+        assert_eq!(format(Compact::short(), 1234.0), "$1.2K");
+        assert_eq!(format(Compact::short(), 1000.0), "$1K");
+        assert_eq!(format(Compact::short(), 15_500.0), "$16K");
+        assert_eq!(format(Compact::short(), 999_999.0), "$1M");
+        assert_eq!(format(Compact::short(), -12e9), "-$12B");
+        assert_eq!(format(Compact::short(), 5e15), "$5,000T");
+        assert_eq!(format(Compact::short(), 999.999), "$1K");
+        assert_eq!(format(Compact::short(), 999.0), "$999.00");
+        assert_eq!(format(Compact::short(), 0.0), "$0.00");
+        assert_eq!(format(Compact::short(), f64::NAN), "$NaN");
+    }
+
+    #[test]
+    fn settings_test() {
+        let mut compact = Compact::long();
+        compact.set_significant_digits(4);
+        assert_eq!(format(compact.clone(), 1_234_567.0), "$1.235 million");
+        compact.set_significant_digits(0);
+        assert_eq!(format(compact, 1_634_567.0), "$2 million");
+
+        let mut ac = Accounting::new_from_seperator("€", 2, ".", ",");
+        ac.set_compact(Some(Compact::default()));
+        ac.set_rounding_mode(RoundingMode::Floor);
+        assert_eq!(ac.format_money(1999), "€1,9K");
+        ac.set_format("{v}{nbsp}{s}").unwrap();
+        assert_eq!(ac.format_money(-1234567), "-1,3M\u{a0}€");
+        assert_eq!(ac.format_column(&[1500, 25_000, -3]), [" 1,5K\u{a0}€", "25K  \u{a0}€", "-3,00\u{a0}€"]);
+
+        assert_eq!(format(Compact::new(&[]), 1e6), "$1,000,000.00");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let json = r#"{"scales": [[6, "M"], [3, "K"]], "threshold": 3, "significant_digits": 2}"#;
+        let compact: Compact = serde_json::from_str(json).unwrap();
+        assert_eq!(compact, Compact::new(&[(3, "K"), (6, "M")]));
+        assert_eq!(format(compact.clone(), 1_500_000.0), "$1.5M");
+        let json = serde_json::to_string(&compact).unwrap();
+        assert_eq!(json, r#"{"scales":[[3,"K"],[6,"M"]],"threshold":3,"significant_digits":2}"#);
+
+        let json = r#"{"scales": [[3, "K"]], "threshold": 3, "significant_digits": 0}"#;
+        let err = serde_json::from_str::<Compact>(json).unwrap_err();
+        assert!(err.to_string().starts_with("significant digits of compact notation are 0"));
+    }
+}
@@ -0,0 +1,515 @@
+//! Validation of Accounting settings that come from configuration.
+//!
+//! [AccountingBuilder] sets the fields of [Accounting] by name and checks them in
+//! [build](AccountingBuilder::build), reporting the first problem as a [ConfigError].
+//!
+//! With feature `serde`, [Accounting] serializes to its settings, with the format strings as text.
+//! Missing fields take the values of [Accounting::default], and deserializing checks the settings
+//! like [AccountingBuilder::build].
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! # use accounting::Accounting;
+//! let json = r#"{"symbol": "€", "precision": 2, "thousand": ".", "decimal": ",", "format_positive": "{v} {s}"}"#;
+//! let ac: Accounting = serde_json::from_str(json).unwrap();
+//! assert_eq!(ac.format_money(1234.5), "1.234,50 €");
+//! assert_eq!(ac.format_money(-1234.5), "-1.234,50 €");
+//!
+//! let json = r#"{"thousand": ".", "decimal": "."}"#;
+//! let err = serde_json::from_str::<Accounting>(json).unwrap_err();
+//! assert_eq!(err.to_string(), "thousand and decimal separators are both \".\"");
+//! # }
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::template::{Template, TemplateError, Token};
+use crate::{Accounting, Compact, Grouping, RoundingMode};
+
+/// Largest precision accepted by [AccountingBuilder::build], the most decimal places a
+/// `rust_decimal::Decimal` can hold. Beyond it, formatting only appends zeros.
+pub const MAX_PRECISION: usize = 28;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The format string of the named field is malformed.
+    Template(&'static str, TemplateError),
+    /// The format string of the named field has no `{v}`, so the value would not be shown.
+    MissingValue(&'static str),
+    /// The thousand and decimal separators are the same, so formatted values cannot be read back.
+    SameSeparators(String),
+    /// The named separator contains a digit, so it cannot be told apart from the value.
+    SeparatorDigit(&'static str, String),
+    /// The precision is more than [MAX_PRECISION].
+    Precision(usize),
+    /// The minimum fraction digits are more than the maximum, the precision.
+    FractionDigits(usize, usize),
+    /// The significant digits of the compact notation are 0.
+    SignificantDigits,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigError::Template(field, ref e) => write!(f, "{}: {}", field, e),
+            ConfigError::MissingValue(field) => write!(f, "{}: no {{v}} placeholder", field),
+            ConfigError::SameSeparators(ref separator) => {
+                write!(f, "thousand and decimal separators are both {:?}", separator)
+            }
+            ConfigError::SeparatorDigit(field, ref separator) => {
+                write!(f, "{} separator {:?} contains a digit", field, separator)
+            }
+            ConfigError::Precision(precision) => {
+                write!(f, "precision {} is more than {}", precision, MAX_PRECISION)
+            }
+            ConfigError::FractionDigits(min, max) => {
+                write!(f, "min fraction digits {} are more than max fraction digits {}", min, max)
+            }
+            ConfigError::SignificantDigits => write!(f, "significant digits of compact notation are 0"),
+        }
+    }
+}
+
+impl error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ConfigError::Template(_, ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Builds an [Accounting] from named settings, see [Accounting::builder].
+///
+/// Settings that are not set keep the values of [Accounting::default]. Like
+/// [Accounting::set_format], a negative format that is not set is the positive format with a
+/// leading `-`, unless it places the sign with `{sign}`, and a zero format that is not set is the
+/// positive format.
+///
+/// [build](AccountingBuilder::build) rejects:
+/// - malformed format strings,
+/// - positive and negative format strings without `{v}`, the zero format may leave it out,
+/// - identical thousand and decimal separators, and separators that contain a digit,
+/// - a precision above [MAX_PRECISION], or minimum fraction digits above the precision.
+///
+/// # Examples
+///
+/// ```
+/// # use accounting::{Accounting, ConfigError};
+/// let ac = Accounting::builder()
+///     .symbol("€")
+///     .precision(2)
+///     .thousand_separator(".")
+///     .decimal_separator(",")
+///     .format_positive("{v} {s}")
+///     .format_negative("({v} {s})")
+///     .build()
+///     .unwrap();
+/// assert_eq!(ac.format_money(-1234.5), "(1.234,50 €)");
+/// assert_eq!(ac.format_money(0), "0,00 €");
+///
+/// let err = Accounting::builder().thousand_separator(".").decimal_separator(".").build();
+/// assert_eq!(err, Err(ConfigError::SameSeparators(".".to_string())));
+/// let err = Accounting::builder().format_negative("({s})").build();
+/// assert_eq!(err, Err(ConfigError::MissingValue("format_negative")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccountingBuilder {
+    symbol: String,
+    code: String,
+    name: String,
+    precision: usize,
+    min_fraction: Option<usize>,
+    thousand: String,
+    decimal: String,
+    format_positive: String,
+    format_negative: Option<String>,
+    format_zero: Option<String>,
+    grouping: Grouping,
+    rounding: RoundingMode,
+    compact: Option<Compact>,
+}
+
+impl Default for AccountingBuilder {
+    fn default() -> Self {
+        AccountingBuilder::new()
+    }
+}
+
+impl AccountingBuilder {
+    /// Returns a builder with the settings of [Accounting::default].
+    pub fn new() -> Self {
+        let ac = Accounting::default();
+        AccountingBuilder {
+            format_positive: ac.format_positive.as_str().to_string(),
+            format_negative: None,
+            format_zero: None,
+            symbol: ac.symbol,
+            code: ac.code,
+            name: ac.name,
+            precision: ac.precision,
+            min_fraction: None,
+            thousand: ac.thousand,
+            decimal: ac.decimal,
+            grouping: ac.grouping,
+            rounding: ac.rounding,
+            compact: ac.compact,
+        }
+    }
+
+    /// Sets the currency symbol used by `{s}`.
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = symbol.to_string();
+        self
+    }
+
+    /// Sets the currency code used by `{c}`.
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = code.to_string();
+        self
+    }
+
+    /// Sets the currency name used by `{n}`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Sets the number of decimal places, the maximum number of fraction digits.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the minimum number of fraction digits, see [Accounting::set_min_fraction_digits].
+    /// If it is not set, it is the precision.
+    pub fn min_fraction_digits(mut self, digits: usize) -> Self {
+        self.min_fraction = Some(digits);
+        self
+    }
+
+    /// Sets the maximum number of fraction digits, like [AccountingBuilder::precision].
+    pub fn max_fraction_digits(mut self, digits: usize) -> Self {
+        self.precision = digits;
+        self
+    }
+
+    /// Sets the thousand separator.
+    pub fn thousand_separator(mut self, thousand: &str) -> Self {
+        self.thousand = thousand.to_string();
+        self
+    }
+
+    /// Sets the decimal separator.
+    pub fn decimal_separator(mut self, decimal: &str) -> Self {
+        self.decimal = decimal.to_string();
+        self
+    }
+
+    /// Sets the format string of all values, like [Accounting::set_format].
+    pub fn format(mut self, format: &str) -> Self {
+        self.format_positive = format.to_string();
+        self.format_negative = None;
+        self.format_zero = None;
+        self
+    }
+
+    /// Sets the format string for positive values.
+    pub fn format_positive(mut self, format: &str) -> Self {
+        self.format_positive = format.to_string();
+        self
+    }
+
+    /// Sets the format string for negative values.
+    pub fn format_negative(mut self, format: &str) -> Self {
+        self.format_negative = Some(format.to_string());
+        self
+    }
+
+    /// Sets the format string for zero values.
+    pub fn format_zero(mut self, format: &str) -> Self {
+        self.format_zero = Some(format.to_string());
+        self
+    }
+
+    /// Sets the digit grouping of the integer part.
+    pub fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets the rounding of digits beyond the precision.
+    pub fn rounding_mode(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the compact notation of large values.
+    pub fn compact(mut self, compact: Option<Compact>) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Checks the settings and returns the Accounting, or the first problem found.
+    pub fn build(self) -> Result<Accounting, ConfigError> {
+        if self.precision > MAX_PRECISION {
+            return Err(ConfigError::Precision(self.precision));
+        }
+        let min_fraction = self.min_fraction.unwrap_or(self.precision);
+        if min_fraction > self.precision {
+            return Err(ConfigError::FractionDigits(min_fraction, self.precision));
+        }
+        for (field, separator) in [("thousand", &self.thousand), ("decimal", &self.decimal)] {
+            if separator.chars().any(|c| c.is_ascii_digit()) {
+                return Err(ConfigError::SeparatorDigit(field, separator.clone()));
+            }
+        }
+        if !self.decimal.is_empty() && self.thousand == self.decimal {
+            return Err(ConfigError::SameSeparators(self.decimal));
+        }
+
+        let template = |field, source: &str| Template::parse(source).map_err(|e| ConfigError::Template(field, e));
+        let format_positive = template("format_positive", &self.format_positive)?;
+        let format_negative = match self.format_negative {
+            Some(ref format) => template("format_negative", format)?,
+            None if format_positive.has_sign() => format_positive.clone(),
+            None => template("format_negative", &format!("-{}", self.format_positive))?,
+        };
+        let format_zero = match self.format_zero {
+            Some(ref format) => template("format_zero", format)?,
+            None => format_positive.clone(),
+        };
+        for (field, format) in [("format_positive", &format_positive), ("format_negative", &format_negative)] {
+            if !format.tokens().contains(&Token::Value) {
+                return Err(ConfigError::MissingValue(field));
+            }
+        }
+
+        Ok(Accounting {
+            symbol: self.symbol,
+            code: self.code,
+            name: self.name,
+            precision: self.precision,
+            min_fraction,
+            thousand: self.thousand,
+            decimal: self.decimal,
+            format_positive,
+            format_negative,
+            format_zero,
+            grouping: self.grouping,
+            rounding: self.rounding,
+            compact: self.compact,
+        })
+    }
+}
+
+impl Accounting {
+    /// Returns an [AccountingBuilder] with the default settings.
+    pub fn builder() -> AccountingBuilder {
+        AccountingBuilder::new()
+    }
+}
+
+/// The serialized form of [Accounting].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct AccountingConfig {
+    symbol: String,
+    code: String,
+    name: String,
+    precision: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_fraction_digits: Option<usize>,
+    thousand: String,
+    decimal: String,
+    format_positive: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format_negative: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format_zero: Option<String>,
+    grouping: Grouping,
+    rounding: RoundingMode,
+    compact: Option<Compact>,
+}
+
+#[cfg(feature = "serde")]
+impl Default for AccountingConfig {
+    fn default() -> Self {
+        AccountingConfig::from(Accounting::default())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Accounting> for AccountingConfig {
+    fn from(ac: Accounting) -> Self {
+        // The negative and zero formats are left out when Accounting::builder derives them.
+        let positive = ac.format_positive.as_str();
+        let negative = match ac.format_positive.has_sign() {
+            true => positive.to_string(),
+            false => format!("-{}", positive),
+        };
+        let derived = |format: &str, derived: &str| (format != derived).then(|| format.to_string());
+        AccountingConfig {
+            format_negative: derived(ac.format_negative.as_str(), &negative),
+            format_zero: derived(ac.format_zero.as_str(), positive),
+            format_positive: positive.to_string(),
+            symbol: ac.symbol,
+            code: ac.code,
+            name: ac.name,
+            precision: ac.precision,
+            // Left out when it is the precision, like in Accounting::builder.
+            min_fraction_digits: (ac.min_fraction != ac.precision).then_some(ac.min_fraction),
+            thousand: ac.thousand,
+            decimal: ac.decimal,
+            grouping: ac.grouping,
+            rounding: ac.rounding,
+            compact: ac.compact,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<AccountingConfig> for Accounting {
+    type Error = ConfigError;
+
+    fn try_from(config: AccountingConfig) -> Result<Self, ConfigError> {
+        let mut builder = Accounting::builder()
+            .symbol(&config.symbol)
+            .code(&config.code)
+            .name(&config.name)
+            .precision(config.precision)
+            .thousand_separator(&config.thousand)
+            .decimal_separator(&config.decimal)
+            .format_positive(&config.format_positive)
+            .grouping(config.grouping)
+            .rounding_mode(config.rounding)
+            .compact(config.compact);
+        if let Some(format) = config.format_negative {
+            builder = builder.format_negative(&format);
+        }
+        if let Some(format) = config.format_zero {
+            builder = builder.format_zero(&format);
+        }
+        if let Some(digits) = config.min_fraction_digits {
+            builder = builder.min_fraction_digits(digits);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_PRECISION;
+    use crate::{Accounting, AccountingBuilder, Compact, ConfigError, Grouping, RoundingMode, TemplateError};
+
+    #[test]
+    fn builder_test() {
+        assert_eq!(AccountingBuilder::new().build().unwrap(), Accounting::default());
+
+        let ac = Accounting::builder()
+            .symbol("₹")
+            .code("INR")
+            .name("Indian Rupee")
+            .precision(2)
+            .format("{v}{sign} {c}")
+            .grouping(Grouping::indian())
+            .rounding_mode(RoundingMode::HalfUp)
+            .build()
+            .unwrap();
+        assert_eq!(ac.format_money(-1234567.125), "12,34,567.13- INR");
+        assert_eq!(ac.format_negative(), "{v}{sign} {c}");
+
+        let ac = Accounting::builder().format("{s} {v}").format_zero("{s} --").compact(Some(Compact::short())).build().unwrap();
+        assert_eq!((ac.format_money(-1500), ac.format_money(0)), ("-$ 1.5K".to_string(), "$ --".to_string()));
+        assert_eq!(Accounting::builder().precision(MAX_PRECISION).build().unwrap().format_money(1), format!("$1.{}", "0".repeat(28)));
+
+        let ac = Accounting::builder().min_fraction_digits(0).max_fraction_digits(3).build().unwrap();
+        assert_eq!((ac.format_money(7), ac.format_money(0.125)), ("$7".to_string(), "$0.125".to_string()));
+    }
+
+    #[test]
+    fn builder_error_test() {
+        let error = |builder: AccountingBuilder| builder.build().unwrap_err();
+        assert_eq!(error(Accounting::builder().precision(29)), ConfigError::Precision(29));
+        assert_eq!(error(Accounting::builder().precision(2).min_fraction_digits(3)), ConfigError::FractionDigits(3, 2));
+        assert_eq!(error(Accounting::builder().thousand_separator("1")), ConfigError::SeparatorDigit("thousand", "1".to_string()));
+        assert_eq!(error(Accounting::builder().decimal_separator(",0")), ConfigError::SeparatorDigit("decimal", ",0".to_string()));
+        assert_eq!(error(Accounting::builder().decimal_separator(",")), ConfigError::SameSeparators(",".to_string()));
+        assert_eq!(error(Accounting::builder().format("{s}")), ConfigError::MissingValue("format_positive"));
+        assert_eq!(error(Accounting::builder().format_negative("-{s}{{v}}")), ConfigError::MissingValue("format_negative"));
+        assert_eq!(
+            error(Accounting::builder().format_zero("{s} {x}")),
+            ConfigError::Template("format_zero", TemplateError::UnknownPlaceholder("x".to_string()))
+        );
+        assert_eq!(error(Accounting::builder().format("{s}{v")), ConfigError::Template("format_positive", TemplateError::UnclosedBrace(3)));
+
+        // Without a decimal separator the thousand separator may be empty as well.
+        assert!(Accounting::builder().thousand_separator("").decimal_separator("").build().is_ok());
+        // The zero format may leave out the value.
+        assert!(Accounting::builder().format_zero("--").build().is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trip_test() {
+        let mut ac = Accounting::for_currency("INR").unwrap();
+        ac.set_format_negative("({s}{v})").unwrap();
+        ac.set_format_zero("{s} --").unwrap();
+        ac.set_grouping(Grouping::indian());
+        ac.set_rounding_mode(RoundingMode::HalfUp);
+        ac.set_compact(Some(Compact::long()));
+        let json = serde_json::to_string(&ac).unwrap();
+        let copy: Accounting = serde_json::from_str(&json).unwrap();
+        assert_eq!(copy, ac);
+
+        let json = serde_json::to_value(Accounting::new_from("$", 2)).unwrap();
+        assert!(json.get("format_negative").is_none() && json.get("format_zero").is_none());
+        assert_eq!(json["grouping"]["primary"], 3);
+        assert_eq!(json["rounding"], "HalfEven");
+        assert_eq!(json["compact"], serde_json::Value::Null);
+        assert!(json.get("min_fraction_digits").is_none());
+
+        let mut ac = Accounting::new_from("$", 4);
+        ac.set_min_fraction_digits(1);
+        let json = serde_json::to_string(&ac).unwrap();
+        assert!(json.contains(r#""precision":4,"min_fraction_digits":1,"#));
+        assert_eq!(serde_json::from_str::<Accounting>(&json).unwrap(), ac);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_test() {
+        let ac: Accounting = serde_json::from_str("{}").unwrap();
+        assert_eq!(ac, Accounting::default());
+        // The negative and zero formats are derived from the positive one, like in the builder.
+        let ac: Accounting = serde_json::from_str(r#"{"format_positive": "{v} {s}"}"#).unwrap();
+        assert_eq!((ac.format_money(-5), ac.format_money(0)), ("-5 $".to_string(), "0 $".to_string()));
+        assert_eq!(ac, Accounting::builder().format("{v} {s}").build().unwrap());
+        let ac: Accounting = serde_json::from_str(r#"{"format_positive": "{v}{sign} {s}"}"#).unwrap();
+        assert_eq!(ac.format_money(-5), "5- $");
+        let ac: Accounting = serde_json::from_str(r#"{"precision": 1, "rounding": "Floor"}"#).unwrap();
+        assert_eq!(ac.format_money(-1.25), "-$1.3");
+
+        let error = |json: &str| serde_json::from_str::<Accounting>(json).unwrap_err().to_string();
+        assert!(error(r#"{"format_negative": "-{s}{v"}"#).starts_with("format_negative: unclosed '{' at 4"));
+        assert!(error(r#"{"format_zero": "{x}"}"#).starts_with("format_zero: unknown placeholder {x}"));
+        assert!(error(r#"{"thousand": ",", "decimal": ","}"#).starts_with("thousand and decimal separators are both \",\""));
+        assert!(error(r#"{"symbl": "$"}"#).starts_with("unknown field `symbl`"));
+        assert!(error(r#"{"format_negative": "({s})"}"#).starts_with("format_negative: no {v} placeholder"));
+        assert!(error(r#"{"precision": 30}"#).starts_with("precision 30 is more than 28"));
+        assert!(error(r#"{"precision": 1, "min_fraction_digits": 2}"#).starts_with("min fraction digits 2 are more than max fraction digits 1"));
+    }
+
+    #[test]
+    fn config_error_test() {
+        let err = ConfigError::Template("format_zero", TemplateError::UnmatchedBrace(0));
+        assert_eq!(err.to_string(), "format_zero: unmatched '}' at 0");
+        assert!(std::error::Error::source(&err).is_some());
+        assert_eq!(ConfigError::SeparatorDigit("decimal", "0".to_string()).to_string(), "decimal separator \"0\" contains a digit");
+        assert!(std::error::Error::source(&ConfigError::Precision(29)).is_none());
+    }
+}
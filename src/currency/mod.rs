@@ -0,0 +1,237 @@
+//! ISO 4217 currency registry.
+//!
+//! The same table is used by [unformat](crate::unformat) to find separators, and can be
+//! searched by alphabetic code, numeric code or symbol.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Currency;
+//! let eur = Currency::from_code("eur").unwrap();
+//! assert_eq!(eur.code(), "EUR");
+//! assert_eq!(eur.numeric(), Some(978));
+//! assert_eq!(eur.name(), "Euro");
+//! assert_eq!(eur.exponent(), 2);
+//! assert_eq!(eur.symbol(), "€");
+//!
+//! assert_eq!(Currency::from_numeric(840).unwrap().code(), "USD");
+//! assert!(Currency::from_symbol("$").iter().any(|c| c.code() == "USD"));
+//! assert!(Currency::all().count() > 100);
+//! ```
+
+mod table;
+
+use std::error;
+use std::fmt;
+use table::CURRENCIES;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurrencyError {
+    /// No currency with this alphabetic code.
+    UnknownCode(String),
+}
+
+impl fmt::Display for CurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CurrencyError::UnknownCode(ref code) => write!(f, "unknown currency code {}", code),
+        }
+    }
+}
+
+impl error::Error for CurrencyError {}
+
+/// A currency of the registry.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    numeric: u16,
+    name: &'static str,
+    exponent: u32,
+    thousands_separator: &'static str,
+    decimal_separator: &'static str,
+    space_separator: &'static str,
+    utf_code_points: &'static str,
+    html_symbol: &'static str,
+    symbol: &'static str,
+    symbol_first: bool,
+}
+
+impl Currency {
+    #[allow(clippy::too_many_arguments)]
+    const fn new(
+        code: &'static str,
+        numeric: u16,
+        name: &'static str,
+        exponent: u32,
+        thousands_separator: &'static str,
+        decimal_separator: &'static str,
+        space_separator: &'static str,
+        utf_code_points: &'static str,
+        html_symbol: &'static str,
+        symbol: &'static str,
+        symbol_first: bool,
+    ) -> Self {
+        Currency {
+            code, numeric, name, exponent, thousands_separator, decimal_separator,
+            space_separator, utf_code_points, html_symbol, symbol, symbol_first,
+        }
+    }
+
+    /// Looks up a currency by its alphabetic code, ignoring case.
+    pub fn from_code(code: &str) -> Option<&'static Currency> {
+        let code = code.to_uppercase();
+        CURRENCIES
+            .binary_search_by(|c| c.code.cmp(code.as_str()))
+            .ok()
+            .map(|index| &CURRENCIES[index])
+    }
+
+    /// Looks up a currency by its alphabetic code, returning an error for unknown codes.
+    pub fn try_from_code(code: &str) -> Result<&'static Currency, CurrencyError> {
+        Self::from_code(code).ok_or_else(|| CurrencyError::UnknownCode(code.to_string()))
+    }
+
+    /// Looks up a currency by its numeric code.
+    pub fn from_numeric(numeric: u16) -> Option<&'static Currency> {
+        if numeric == 0 {
+            return None;
+        }
+        CURRENCIES.iter().find(|c| c.numeric == numeric)
+    }
+
+    /// Returns every currency using `symbol`, in code order.
+    pub fn from_symbol(symbol: &str) -> Vec<&'static Currency> {
+        CURRENCIES.iter().filter(|c| c.symbols().iter().any(|s| s == symbol)).collect()
+    }
+
+    /// Iterates over all currencies, in code order.
+    pub fn all() -> impl Iterator<Item = &'static Currency> {
+        CURRENCIES.iter()
+    }
+
+    /// Returns the ISO 4217 alphabetic code, like `USD`.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the ISO 4217 numeric code, if the currency has one.
+    pub fn numeric(&self) -> Option<u16> {
+        if self.numeric == 0 {
+            None
+        } else {
+            Some(self.numeric)
+        }
+    }
+
+    /// Returns the English name of the currency.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the minor unit exponent, the number of decimal places of the currency.
+    pub fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    /// Returns the common symbol, like `$` or `kr`.
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Returns the symbol built from the Unicode code points of the table, if any.
+    pub fn unicode_symbol(&self) -> Option<String> {
+        if self.utf_code_points.is_empty() {
+            return None;
+        }
+        self.utf_code_points
+            .split(' ')
+            .map(|hex| u32::from_str_radix(hex, 16).ok().and_then(char::from_u32))
+            .collect()
+    }
+
+    /// Returns the HTML entity of the symbol, if any.
+    pub fn html_symbol(&self) -> Option<&'static str> {
+        if self.html_symbol.is_empty() {
+            None
+        } else {
+            Some(self.html_symbol)
+        }
+    }
+
+    /// Returns the distinct symbol variants: the common symbol and the Unicode symbol.
+    pub fn symbols(&self) -> Vec<String> {
+        let mut symbols = vec![self.symbol.to_string()];
+        if let Some(symbol) = self.unicode_symbol() {
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        symbols
+    }
+
+    /// Returns the thousands separator, empty if the currency table has none.
+    pub fn thousands_separator(&self) -> &'static str {
+        self.thousands_separator
+    }
+
+    /// Returns the decimal separator, empty if the currency table has none.
+    pub fn decimal_separator(&self) -> &'static str {
+        self.decimal_separator
+    }
+
+    /// Returns the separator placed between symbol and value.
+    pub fn space_separator(&self) -> &'static str {
+        self.space_separator
+    }
+
+    /// Returns true if the symbol is written before the value.
+    pub fn symbol_first(&self) -> bool {
+        self.symbol_first
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_test() {
+        assert!(CURRENCIES.windows(2).all(|w| w[0].code < w[1].code));
+        let mut numerics: Vec<u16> = CURRENCIES.iter().filter_map(|c| c.numeric()).collect();
+        let len = numerics.len();
+        numerics.sort();
+        numerics.dedup();
+        assert_eq!(numerics.len(), len);
+    }
+
+    #[test]
+    fn lookup_test() {
+        let gbp = Currency::from_code("GBP").unwrap();
+        assert_eq!(gbp.name(), "Pound Sterling");
+        assert_eq!(gbp.numeric(), Some(826));
+        assert_eq!(gbp.html_symbol(), Some("&#x00A3;"));
+        assert_eq!(Currency::from_code("jpy").unwrap().exponent(), 0);
+        assert_eq!(Currency::from_code("BHD").unwrap().exponent(), 3);
+        assert_eq!(Currency::from_code("ZZZ"), None);
+        assert_eq!(Currency::try_from_code("ZZZ"), Err(CurrencyError::UnknownCode("ZZZ".to_string())));
+        assert_eq!(Currency::from_numeric(978).unwrap().code(), "EUR");
+        assert_eq!(Currency::from_numeric(0), None);
+        assert_eq!(Currency::from_numeric(999), None);
+        assert_eq!(Currency::from_code("SAC").unwrap().numeric(), None);
+    }
+
+    #[test]
+    fn symbol_test() {
+        assert_eq!(Currency::from_code("BRL").unwrap().unicode_symbol(), Some("R$".to_string()));
+        assert_eq!(Currency::from_code("CNY").unwrap().symbols(), vec!["¥", "圓"]);
+        assert_eq!(Currency::from_code("AED").unwrap().unicode_symbol(), None);
+
+        let yen: Vec<&str> = Currency::from_symbol("¥").iter().map(|c| c.code()).collect();
+        assert_eq!(yen, vec!["CNY", "JPY"]);
+        let pounds: Vec<&str> = Currency::from_symbol("£").iter().map(|c| c.code()).collect();
+        assert!(pounds.contains(&"GBP") && pounds.contains(&"EGP"));
+        assert!(Currency::from_symbol("no such symbol").is_empty());
+    }
+}
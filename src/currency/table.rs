@@ -0,0 +1,191 @@
+//! ISO 4217 currency data: code, numeric code, name, minor unit, separators and symbols.
+
+use super::Currency;
+
+// Columns: code, numeric code (0 if none), name, minor unit exponent, thousands separator,
+// decimal separator, space separator, UTF code points, HTML symbol, common symbol, symbol first.
+// Rows are sorted by code for binary search.
+pub(super) static CURRENCIES: [Currency; 182] = [
+    Currency::new("AED", 784, "UAE Dirham", 2, ",", ".", " ", "", "", "Dhs.", true),
+    Currency::new("AFA", 4, "Afghani", 0, "", "", "", "060B", "&#x060B;", "؋", true),
+    Currency::new("ALL", 8, "Lek", 2, "", "", "", "", "", "Lek", true),
+    Currency::new("AMD", 51, "Armenian Dram", 2, ",", ".", "", "", "", "֏", false),
+    Currency::new("ANG", 532, "Antillian Guilder", 2, ".", ",", " ", "0192", "&#x0192;", "ƒ", true),
+    Currency::new("AOA", 973, "New Kwanza", 0, "", "", "", "", "", "Kz", true),
+    Currency::new("ARS", 32, "Argentine Peso", 2, ".", ",", "", "20B1", "&#x20B1;", "$", true),
+    Currency::new("ATS", 40, "Schilling", 2, ".", ",", " ", "", "", "öS", true),
+    Currency::new("AUD", 36, "Australian Dollar", 2, " ", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("AWG", 533, "Aruban Guilder", 2, ",", ".", " ", "0192", "&#x0192;", "ƒ", true),
+    Currency::new("AZN", 944, "Azerbaijanian Manat", 2, "", "", "", "", "", "₼", true),
+    Currency::new("BAM", 977, "Convertible Marks", 2, ",", ".", "", "", "", "KM", false),
+    Currency::new("BBD", 52, "Barbados Dollar", 2, "", "", "", "0024", "&#x0024;", "Bds$", true),
+    Currency::new("BDT", 50, "Taka", 2, ",", ".", " ", "", "", "Tk", true),
+    Currency::new("BEF", 56, "Belgian Franc", 0, ".", "", " ", "20A3", "&#x20A3;", "BEF", true),
+    Currency::new("BGN", 975, "Lev", 2, " ", ",", " ", "", "", "лв", false),
+    Currency::new("BHD", 48, "Bahraini Dinar", 3, ",", ".", " ", "", "", "د.ب", true),
+    Currency::new("BIF", 108, "Burundi Franc", 0, "", "", "", "", "", "FBu", true),
+    Currency::new("BMD", 60, "Bermudian Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("BND", 96, "Brunei Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("BOB", 68, "Bolivian Boliviano", 2, ",", ".", "", "", "", "$b", true),
+    Currency::new("BRL", 986, "Brazilian Real", 2, ".", ",", " ", "0052 0024", "R$", "R$", true),
+    Currency::new("BSD", 44, "Bahamian Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("BTN", 64, "Bhutan Ngultrum", 2, "", "", "", "", "", "BTN", true),
+    Currency::new("BWP", 72, "Pula", 2, ",", ".", "", "", "", "P", true),
+    Currency::new("BYR", 974, "Belarussian Ruble", 0, "", "", "", "", "", "p.", true),
+    Currency::new("BZD", 84, "Belize Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("CAD", 124, "Canadian Dollar", 2, ",", ".", "", "0024", "&#x0024;", "CA$", true),
+    Currency::new("CDF", 976, "Franc Congolais", 2, "", "", "", "", "", "FC", true),
+    Currency::new("CHF", 756, "Swiss Franc", 2, "'", ".", " ", "", "", "CHF", true),
+    Currency::new("CLP", 152, "Chilean Peso", 0, ".", "", "", "20B1", "&#x20B1;", "$", true),
+    Currency::new("CNY", 156, "Yuan Renminbi", 2, ",", ".", "", "5713", "&#x5713;", "¥", true),
+    Currency::new("COP", 170, "Colombian Peso", 2, ".", ",", "", "20B1", "&#x20B1;", "$", true),
+    Currency::new("CRC", 188, "Costa Rican Colon", 2, ".", ",", " ", "20A1", "&#x20A1;", "₡", true),
+    Currency::new("CUP", 192, "Cuban Peso", 2, ",", ".", " ", "20B1", "&#x20B1;", "$", true),
+    Currency::new("CVE", 132, "Cape Verde Escudo", 0, "", "", "", "", "", "$", true),
+    Currency::new("CYP", 196, "Cyprus Pound", 2, ".", ",", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("CZK", 203, "Czech Koruna", 2, ".", ",", " ", "", "", "Kč", false),
+    Currency::new("DEM", 276, "Deutsche Mark", 2, ".", ",", "", "", "", "DM", false),
+    Currency::new("DJF", 262, "Djibouti Franc", 0, "", "", "", "", "", "DJF", true),
+    Currency::new("DKK", 208, "Danish Krone", 2, ".", ",", "", "", "", "kr.", true),
+    Currency::new("DOP", 214, "Dominican Peso", 2, ",", ".", " ", "20B1", "&#x20B1;", "$", true),
+    Currency::new("DZD", 12, "Algerian Dinar", 2, "", "", "", "", "", "DA", true),
+    Currency::new("ECS", 218, "Sucre", 0, "", "", "", "", "", "S.", true),
+    Currency::new("EEK", 233, "Kroon", 2, " ", ",", " ", "", "", "kr", false),
+    Currency::new("EGP", 818, "Egyptian Pound", 2, ",", ".", " ", "00A3", "&#x00A3;", "£", true),
+    Currency::new("ERN", 232, "Nakfa", 0, "", "", "", "", "", "NKf", true),
+    Currency::new("ESP", 724, "Spanish Peseta", 0, ".", "", " ", "20A7", "&#x20A7;", "Ptas", false),
+    Currency::new("ETB", 230, "Ethiopian Birr", 0, "", "", "", "", "", "BR", true),
+    Currency::new("EUR", 978, "Euro", 2, ".", ",", "", "20AC", "&#x20AC;", "€", true),
+    Currency::new("FIM", 246, "Markka", 2, " ", ",", " ", "", "", "mk", false),
+    Currency::new("FJD", 242, "Fiji Dollar", 0, "", "", "", "0024", "&#x0024;", "FJ$", true),
+    Currency::new("FKP", 238, "Pound", 0, "", "", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("FRF", 250, "French Franc", 2, " ", ",", " ", "20A3", "&#x20A3;", "Fr", false),
+    Currency::new("GBP", 826, "Pound Sterling", 2, ",", ".", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("GEL", 981, "Lari", 0, "", "", "", "", "", "GEL", true),
+    Currency::new("GHS", 936, "Cedi", 2, ",", ".", "", "20B5", "&#x20B5;", "₵", true),
+    Currency::new("GIP", 292, "Gibraltar Pound", 2, ",", ".", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("GMD", 270, "Dalasi", 0, "", "", "", "", "", "GMD", true),
+    Currency::new("GNF", 324, "Guinea Franc", 0, "", "", "", "", "", "FG", true),
+    Currency::new("GRD", 300, "Drachma", 2, ".", ",", " ", "20AF", "&#x20AF;", "GRD", false),
+    Currency::new("GTQ", 320, "Quetzal", 2, ",", ".", "", "", "", "Q.", true),
+    Currency::new("GWP", 624, "Guinea-Bissau Peso", 0, "", "", "", "", "", "GWP", true),
+    Currency::new("GYD", 328, "Guyana Dollar", 0, "", "", "", "0024", "&#x0024;", "$", true),
+    Currency::new("HKD", 344, "Hong Kong Dollar", 2, ",", ".", "", "0024", "&#x0024;", "HK$", true),
+    Currency::new("HNL", 340, "Lempira", 2, ",", ".", " ", "", "", "L", true),
+    Currency::new("HRK", 191, "Kuna", 2, ".", ",", " ", "", "", "kn", false),
+    Currency::new("HTG", 332, "Gourde", 0, "", "", "", "", "", "G", true),
+    Currency::new("HUF", 348, "Forint", 0, ".", "", " ", "", "", "Ft", false),
+    Currency::new("IDR", 360, "Rupiah", 0, ".", ",", "", "", "", "Rp", true),
+    Currency::new("IEP", 372, "Irish Pound", 2, ",", ".", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("ILS", 376, "New Israeli Sheqel", 2, ",", ".", " ", "20AA", "&#x20AA;", "₪", false),
+    Currency::new("INR", 356, "Indian Rupee", 2, ",", ".", "", "20A8", "&#x20A8;", "₹", true),
+    Currency::new("IQD", 368, "Iraqi Dinar", 3, "", "", "", "", "", "د.ع", true),
+    Currency::new("IRR", 364, "Iranian Rial", 2, ",", ".", " ", "FDFC", "&#xFDFC;", "﷼", true),
+    Currency::new("ISK", 352, "Iceland Krona", 2, ".", ",", " ", "", "", "kr", false),
+    Currency::new("ITL", 380, "Italian Lira", 0, ".", "", " ", "20A4", "&#x20A4;", "L.", true),
+    Currency::new("JMD", 388, "Jamaican Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("JOD", 400, "Jordanian Dinar", 3, ",", ".", " ", "", "", "JD", true),
+    Currency::new("JPY", 392, "Yen", 0, ",", "", "", "00A5", "&#x00A5;", "¥", true),
+    Currency::new("KES", 404, "Kenyan Shilling", 2, ",", ".", "", "", "", "Ksh", true),
+    Currency::new("KGS", 417, "Som", 0, "", "", "", "", "", "лв", true),
+    Currency::new("KHR", 116, "Riel", 2, "", "", "", "17DB", "&#x17DB;", "៛", true),
+    Currency::new("KMF", 174, "Comoro Franc", 0, "", "", "", "", "", "KMF", true),
+    Currency::new("KPW", 408, "North Korean Won", 0, "", "", "", "20A9", "&#x20A9;", "₩", true),
+    Currency::new("KRW", 410, "Won", 0, ",", "", "", "20A9", "&#x20A9;", "₩", true),
+    Currency::new("KWD", 414, "Kuwaiti Dinar", 3, ",", ".", " ", "", "", "ك", true),
+    Currency::new("KYD", 136, "Cayman Islands Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("KZT", 398, "Tenge", 0, "", "", "", "", "", "₸", true),
+    Currency::new("LAK", 418, "Kip", 0, "", "", "", "20AD", "&#x20AD;", "₭", true),
+    Currency::new("LBP", 422, "Lebanese Pound", 0, " ", "", "", "00A3", "&#x00A3;", "ل.ل", false),
+    Currency::new("LKR", 144, "Sri Lanka Rupee", 0, "", "", "", "0BF9", "&#x0BF9;", "₨", true),
+    Currency::new("LRD", 430, "Liberian Dollar", 0, "", "", "", "0024", "&#x0024;", "$", true),
+    Currency::new("LSL", 426, "Lesotho Maloti", 0, "", "", "", "", "", "LSL", true),
+    Currency::new("LTL", 440, "Lithuanian Litas", 2, " ", ",", " ", "", "", "Lt", false),
+    Currency::new("LUF", 442, "Luxembourg Franc", 0, "'", "", " ", "20A3", "&#x20A3;", "F", false),
+    Currency::new("LVL", 428, "Latvian Lats", 2, ",", ".", " ", "", "", "Ls", true),
+    Currency::new("LYD", 434, "Libyan Dinar", 0, "", "", "", "", "", "LD", true),
+    Currency::new("MAD", 504, "Moroccan Dirham", 0, "", "", "", "", "", "MAD", true),
+    Currency::new("MDL", 498, "Moldovan Leu", 0, "", "", "", "", "", "MDL", true),
+    Currency::new("MGF", 450, "Malagasy Franc", 0, "", "", "", "", "", "MF", true),
+    Currency::new("MKD", 807, "Denar", 2, ",", ".", " ", "", "", "ден", false),
+    Currency::new("MMK", 104, "Kyat", 0, "", "", "", "", "", "K", true),
+    Currency::new("MNT", 496, "Tugrik", 0, "", "", "", "20AE", "&#x20AE;", "₮", true),
+    Currency::new("MOP", 446, "Pataca", 0, "", "", "", "", "", "MOP$", true),
+    Currency::new("MRO", 478, "Ouguiya", 0, "", "", "", "", "", "MRO", true),
+    Currency::new("MTL", 470, "Maltese Lira", 2, ",", ".", "", "20A4", "&#x20A4;", "Lm", true),
+    Currency::new("MUR", 480, "Mauritius Rupee", 0, ",", "", "", "20A8", "&#x20A8;", "Rs", true),
+    Currency::new("MVR", 462, "Rufiyaa", 0, "", "", "", "", "", "MVR", true),
+    Currency::new("MWK", 454, "Kwacha", 2, ",", ".", "", "", "", "MK", true),
+    Currency::new("MXN", 484, "Mexican Peso", 2, ",", ".", " ", "0024", "&#x0024;", "$", true),
+    Currency::new("MYR", 458, "Malaysian Ringgit", 2, ",", ".", "", "", "", "RM", true),
+    Currency::new("MZN", 943, "Metical", 2, ".", ",", " ", "", "", "Mt", false),
+    Currency::new("NAD", 516, "Namibian Dollar", 0, "", "", "", "0024", "&#x0024;", "$", true),
+    Currency::new("NGN", 566, "Naira", 2, ",", ".", ".", "20A6", "&#x20A6;", "₦", true),
+    Currency::new("NIO", 558, "Cordoba Oro", 0, "", "", "", "", "", "C$", true),
+    Currency::new("NLG", 528, "Netherlands Guilder", 2, ".", ",", " ", "0192", "&#x0192;", "ƒ", true),
+    Currency::new("NOK", 578, "Norwegian Krone", 2, ".", ",", " ", "kr", "kr", "kr", true),
+    Currency::new("NPR", 524, "Nepalese Rupee", 2, ",", ".", " ", "20A8", "&#x20A8;", "Rs.", true),
+    Currency::new("NZD", 554, "New Zealand Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("OMR", 512, "Rial Omani", 3, ",", ".", " ", "FDFC", "&#xFDFC;", "RO", true),
+    Currency::new("PAB", 590, "Balboa", 0, "", "", "", "", "", "B/.", true),
+    Currency::new("PEN", 604, "Nuevo Sol", 2, ",", ".", " ", "S/.", "S/.", "S/.", true),
+    Currency::new("PGK", 598, "Kina", 0, "", "", "", "", "", "K", true),
+    Currency::new("PHP", 608, "Philippine Peso", 2, ",", ".", "", "20B1", "&#x20B1;", "₱", true),
+    Currency::new("PKR", 586, "Pakistan Rupee", 2, ",", ".", "", "20A8", "&#x20A8;", "Rs", true),
+    Currency::new("PLN", 985, "Zloty", 2, " ", ",", " ", "", "", "zł", false),
+    Currency::new("PTE", 620, "Portuguese Escudo", 0, ".", "", " ", "", "", "$", false),
+    Currency::new("PYG", 600, "Guarani", 0, "", "", "", "20B2", "&#x20B2;", "Gs", true),
+    Currency::new("QAR", 634, "Qatari Rial", 0, "", "", "", "FDFC", "&#xFDFC;", "﷼", true),
+    Currency::new("RON", 946, "Leu", 2, ".", ",", " ", "", "", "lei", false),
+    Currency::new("RSD", 941, "Serbian Dinar", 2, "", "", "", "", "", "РСД", false),
+    Currency::new("RUB", 643, "Russian Ruble", 2, ".", ",", "", "0440 0443 0431", "&#x0440;&#x0443;&#x0431;", "₽", true),
+    Currency::new("RWF", 646, "Rwanda Franc", 0, "", "", "", "", "", "RWF", true),
+    Currency::new("SAC", 0, "S. African Rand Commerc.", 0, "", "", "", "", "", "SAC", true),
+    Currency::new("SAR", 682, "Saudi Riyal", 2, ",", ".", " ", "FDFC", "&#xFDFC;", "﷼", true),
+    Currency::new("SBD", 90, "Solomon Islands Dollar", 0, "", "", "", "0024", "&#x0024;", "$", true),
+    Currency::new("SCR", 690, "Seychelles Rupee", 0, "", "", "", "20A8", "&#x20A8;", "₨", true),
+    Currency::new("SDG", 938, "Sudanese Dinar", 0, "", "", "", "", "", "LSd", true),
+    Currency::new("SDP", 736, "Sudanese Pound", 0, "", "", "", "", "", "SDP", true),
+    Currency::new("SEK", 752, "Swedish Krona", 2, " ", ",", " ", "", "", "kr", false),
+    Currency::new("SGD", 702, "Singapore Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("SHP", 654, "St Helena Pound", 0, "", "", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("SIT", 705, "Tolar", 2, ".", ",", " ", "", "", "SIT", false),
+    Currency::new("SKK", 703, "Slovak Koruna", 2, " ", ",", " ", "", "", "Sk", false),
+    Currency::new("SLL", 694, "Leone", 0, "", "", "", "", "", "Le", true),
+    Currency::new("SOS", 706, "Somali Shilling", 0, "", "", "", "", "", "S", true),
+    Currency::new("SRG", 740, "Surinam Guilder", 0, "", "", "", "", "", "SRG", true),
+    Currency::new("STD", 678, "Dobra", 0, "", "", "", "", "", "DB", true),
+    Currency::new("SVC", 222, "El Salvador Colon", 2, ",", ".", "", "20A1", "&#x20A1;", "¢", true),
+    Currency::new("SYP", 760, "Syrian Pound", 0, "", "", "", "00A3", "&#x00A3;", "£", true),
+    Currency::new("SZL", 748, "Lilangeni", 2, "", ".", "", "", "", "E", true),
+    Currency::new("THB", 764, "Baht", 2, ",", ".", " ", "0E3F", "&#x0E3F;", "Bt", false),
+    Currency::new("TJR", 762, "Tajik Ruble", 0, "", "", "", "", "", "TJR", true),
+    Currency::new("TJS", 972, "Somoni", 0, "", "", "", "", "", "TJS", true),
+    Currency::new("TMM", 795, "Manat", 0, "", "", "", "", "", "T", true),
+    Currency::new("TND", 788, "Tunisian Dinar", 3, "", "", "", "", "", "TND", true),
+    Currency::new("TOP", 776, "Pa'anga", 2, ",", ".", " ", "", "", "$", true),
+    Currency::new("TPE", 626, "Timor Escudo", 0, "", "", "", "", "", "TPE", true),
+    Currency::new("TRY", 949, "Turkish Lira", 0, ",", "", "", "20A4", "&#x20A4;", "TL", false),
+    Currency::new("TTD", 780, "Trinidad and Tobago Dollar", 0, "", "", "", "0024", "&#x0024;", "TT$", true),
+    Currency::new("TWD", 901, "New Taiwan Dollar", 0, "", "", "", "0024", "&#x0024;", "NT$", true),
+    Currency::new("TZS", 834, "Tanzanian Shilling", 2, ",", ".", " ", "", "", "TZs", false),
+    Currency::new("UAH", 980, "Hryvnia", 2, " ", ",", "", "20B4", "&#x20B4;", "UAH", false),
+    Currency::new("UGX", 800, "Uganda Shilling", 0, "", "", "", "", "", "UGX", true),
+    Currency::new("USD", 840, "US Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("UYU", 858, "Peso Uruguayo", 2, ".", ",", "", "20B1", "&#x20B1;", "$", true),
+    Currency::new("UZS", 860, "Uzbekistan Sum", 0, "", "", "", "", "", "лв", true),
+    Currency::new("VEF", 937, "Bolivar", 2, ".", ",", " ", "", "", "Bs.", true),
+    Currency::new("VND", 704, "Dong", 2, ".", ",", " ", "20AB", "&#x20AB;", "₫", true),
+    Currency::new("VUV", 548, "Vatu", 0, ",", "", "", "", "", "VT", false),
+    Currency::new("WST", 882, "Tala", 0, "", "", "", "", "", "WST", true),
+    Currency::new("XAF", 950, "CFA Franc BEAC", 0, "", "", "", "", "", "$", true),
+    Currency::new("XCD", 951, "East Caribbean Dollar", 2, ",", ".", "", "0024", "&#x0024;", "$", true),
+    Currency::new("XOF", 952, "CFA Franc BCEAO", 0, "", "", "", "", "", "XOF", true),
+    Currency::new("XPF", 953, "CFP Franc", 0, "", "", "", "", "", "XPF", true),
+    Currency::new("YER", 886, "Yemeni Rial", 0, "", "", "", "FDFC", "&#xFDFC;", "﷼", true),
+    Currency::new("YUN", 890, "New Dinar", 0, "", "", "", "", "", "YUN", true),
+    Currency::new("ZAR", 710, "Rand", 2, " ", ".", " ", "0052", "&#x0052;", "R", true),
+    Currency::new("ZMK", 894, "Kwacha", 0, "", "", "", "", "", "ZMK", true),
+    Currency::new("ZRN", 180, "New Zaire", 0, "", "", "", "", "", "ZRN", true),
+    Currency::new("ZWD", 716, "Zimbabwe Dollar", 2, " ", ".", "", "0024", "&#x0024;", "Z$", true),
+];
@@ -0,0 +1,88 @@
+//! Display adapter, so money values can be used with `format!`, `write!` and `println!`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Accounting;
+//! let ac = Accounting::new_from("€", 2);
+//! let line = format!("{:<8}{:>12}", "total", ac.display(1234.5));
+//! assert_eq!(line, "total      €1,234.50");
+//! ```
+
+use std::fmt::{self, Write};
+use crate::{Accounting, FormatNumber};
+
+/// Formats a value as money with the settings of an [Accounting], see [Accounting::display].
+///
+/// Width, fill and alignment of the formatter are honoured, and values are right aligned
+/// by default like numbers. The precision of the formatter is ignored, the precision of the
+/// [Accounting] applies.
+pub struct MoneyDisplay<'a, T> {
+    accounting: &'a Accounting,
+    value: T,
+}
+
+impl<'a, T: FormatNumber> MoneyDisplay<'a, T> {
+    pub(crate) fn new(accounting: &'a Accounting, value: T) -> Self {
+        MoneyDisplay { accounting, value }
+    }
+}
+
+impl<T: FormatNumber> fmt::Display for MoneyDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rounded = self.accounting.round(&self.value);
+        let width = match f.width() {
+            Some(width) => width,
+            None => return self.accounting.write_rounded(f, &rounded),
+        };
+
+        let mut counter = CharCounter(0);
+        self.accounting.write_rounded(&mut counter, &rounded)?;
+        let padding = width.saturating_sub(counter.0);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.accounting.write_rounded(f, &rounded)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the characters written, to compute the padding without buffering the output.
+struct CharCounter(usize);
+
+impl fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::Accounting;
+
+    #[test]
+    fn display_test() {
+        let ac = Accounting::new_from("€", 2);
+        assert_eq!(ac.display(1234.5).to_string(), "€1,234.50");
+        assert_eq!(format!("{}", ac.display(-1)), "-€1.00");
+        assert_eq!(format!("{:12}", ac.display(-1)), "      -€1.00");
+        assert_eq!(format!("{:<12}|", ac.display(-1)), "-€1.00      |");
+        assert_eq!(format!("{:^12}|", ac.display(-1)), "   -€1.00   |");
+        assert_eq!(format!("{:_^11}", ac.display(-1)), "__-€1.00___");
+        assert_eq!(format!("{:3}", ac.display(1234)), "€1,234.00");
+        assert_eq!(format!("{:>w$}", ac.display(0u8), w = 6), " €0.00");
+        assert_eq!(format!("{:.0}", ac.display(1.5)), "€1.50");
+    }
+}
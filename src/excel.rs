@@ -0,0 +1,426 @@
+//! Excel number format codes, like `"$"#,##0.00_);[Red]("$"#,##0.00);"-"`.
+//!
+//! A format code has up to four sections separated by `;`, for positive values, negative values,
+//! zero and text. They map onto the format strings of an Accounting:
+//! - `"text"` and `\c` are literal text, as are the characters `$ - + / ( ) : ! ^ & ' ~ { } < > =` and space.
+//! - `#,##0.00` is the value, a `,` turns on grouping. After `.` the `0`s set the minimum and
+//!   the `0`s and `#`s the maximum number of fraction digits, like `0.0#`.
+//! - `[$€-407]` is the currency symbol `{s}`, colors like `[Red]` are ignored. All sections must
+//!   use the same symbol.
+//! - `_x` is a space and `*x` is ignored, as there is no cell width to fill.
+//! - A zero section made of `?` only, like the `??` in `"-"??`, is written as spaces.
+//! - The text section is ignored.
+//!
+//! Everything else, like `%`, dates, scaling by `,` or conditions, is reported as
+//! [ExcelFormatError::Unsupported]. Like in Excel, values are rounded half away from zero.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Accounting;
+//! let ac = Accounting::from_excel_format(r##""$"#,##0.00_);[Red]("$"#,##0.00);"-""##).unwrap();
+//! assert_eq!(ac.format_money(1234.5), "$1,234.50 ");
+//! assert_eq!(ac.format_money(-1234.5), "($1,234.50)");
+//! assert_eq!(ac.format_money(0), "-");
+//! assert_eq!(ac.to_excel_format(), r##""$"#,##0.00 ;("$"#,##0.00);-"##);
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::template::Token;
+use crate::{Accounting, Grouping, RoundingMode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExcelFormatError {
+    /// A `"` has no closing `"`.
+    UnclosedQuote,
+    /// A `[` has no closing `]`.
+    UnclosedBracket,
+    /// There are more than four sections.
+    TooManySections,
+    /// The token is not supported by Accounting.
+    Unsupported(String),
+}
+
+impl fmt::Display for ExcelFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExcelFormatError::UnclosedQuote => write!(f, "unclosed '\"'"),
+            ExcelFormatError::UnclosedBracket => write!(f, "unclosed '['"),
+            ExcelFormatError::TooManySections => write!(f, "more than four sections"),
+            ExcelFormatError::Unsupported(ref token) => write!(f, "unsupported token {}", token),
+        }
+    }
+}
+
+impl error::Error for ExcelFormatError {}
+
+// Characters Excel displays without quotes.
+const LITERALS: &str = "$-+/():!^&'~{}<>= ";
+const COLORS: [&str; 8] = ["black", "blue", "cyan", "green", "magenta", "red", "white", "yellow"];
+
+/// A section of a format code, turned into a template.
+#[derive(Default)]
+struct Section {
+    template: String,
+    symbol: Option<String>,
+    /// The minimum and maximum number of fraction digits.
+    precision: Option<(usize, usize)>,
+    grouping: bool,
+}
+
+impl Section {
+    fn push_literal(&mut self, c: char) {
+        match c {
+            '{' => self.template.push_str("{{"),
+            '}' => self.template.push_str("}}"),
+            c => self.template.push(c),
+        }
+    }
+
+    fn push_number(&mut self, number: &str, zero: bool) -> Result<(), ExcelFormatError> {
+        let unsupported = || ExcelFormatError::Unsupported(number.to_string());
+        if zero && number.chars().all(|c| c == '?') {
+            self.template.extend(number.chars().map(|_| ' '));
+            return Ok(());
+        }
+        if self.precision.is_some() {
+            return Err(unsupported());
+        }
+
+        let mut parts = number.splitn(2, '.');
+        let integer = parts.next().unwrap_or("");
+        let fraction = parts.next().unwrap_or("");
+        // A trailing `,` scales by a thousand. Optional `#` digits follow the required `0`s.
+        let min = fraction.len() - fraction.trim_start_matches('0').len();
+        if integer.ends_with(',') || fraction[min..].chars().any(|c| c != '#') {
+            return Err(unsupported());
+        }
+        self.precision = Some((min, fraction.len()));
+        self.grouping = integer.contains(',');
+        self.template.push_str("{v}");
+        Ok(())
+    }
+
+    fn push_bracket(&mut self, content: &str) -> Result<(), ExcelFormatError> {
+        let lower = content.to_ascii_lowercase();
+        if let Some(currency) = content.strip_prefix('$') {
+            let symbol = currency.split('-').next().unwrap_or("");
+            if self.symbol.as_deref().is_some_and(|s| s != symbol) {
+                return Err(ExcelFormatError::Unsupported(format!("[{}]", content)));
+            }
+            self.symbol = Some(symbol.to_string());
+            self.template.push_str("{s}");
+        } else if COLORS.contains(&lower.as_str())
+            || lower.strip_prefix("color").is_some_and(|n| n.parse::<u8>().is_ok())
+        {
+            // Colors have no meaning in text.
+        } else {
+            return Err(ExcelFormatError::Unsupported(format!("[{}]", content)));
+        }
+        Ok(())
+    }
+}
+
+/// Splits a format code into its sections, keeping `;` inside quotes and brackets.
+fn split_sections(code: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let (mut start, mut quoted, mut bracket, mut escaped) = (0, false, false, false);
+    for (i, c) in code.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if !quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '[' if !quoted => bracket = true,
+            ']' if !quoted => bracket = false,
+            ';' if !quoted && !bracket => {
+                sections.push(&code[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    sections.push(&code[start..]);
+    sections
+}
+
+fn parse_section(code: &str, zero: bool) -> Result<Section, ExcelFormatError> {
+    let mut section = Section::default();
+    let mut chars = code.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, c)) => section.push_literal(c),
+                    None => return Err(ExcelFormatError::UnclosedQuote),
+                }
+            },
+            '\\' => {
+                if let Some((_, c)) = chars.next() {
+                    section.push_literal(c);
+                }
+            }
+            '_' => {
+                chars.next();
+                section.push_literal(' ');
+            }
+            '*' => {
+                chars.next();
+            }
+            '[' => {
+                let rest = &code[i + 1..];
+                let end = rest.find(']').ok_or(ExcelFormatError::UnclosedBracket)?;
+                section.push_bracket(&rest[..end])?;
+                while chars.next_if(|&(j, _)| j <= i + 1 + end).is_some() {}
+            }
+            '0' | '#' | '?' | '.' | ',' => {
+                let mut end = i + 1;
+                while let Some((j, _)) = chars.next_if(|&(_, c)| "0#?.,".contains(c)) {
+                    end = j + 1;
+                }
+                section.push_number(&code[i..end], zero)?;
+            }
+            c if LITERALS.contains(c) => section.push_literal(c),
+            c => return Err(ExcelFormatError::Unsupported(c.to_string())),
+        }
+    }
+    Ok(section)
+}
+
+/// Writes a template literal, quoting everything but the characters Excel displays as they are.
+fn write_literal(result: &mut String, s: &str) {
+    let mut quoted = false;
+    for c in s.chars() {
+        let bare = "-+() ".contains(c) || c == '"';
+        if bare && quoted {
+            result.push('"');
+            quoted = false;
+        } else if !bare && !quoted {
+            result.push('"');
+            quoted = true;
+        }
+        match c {
+            '"' => result.push_str("\\\""),
+            c => result.push(c),
+        }
+    }
+    if quoted {
+        result.push('"');
+    }
+}
+
+impl Accounting {
+    /// Create Accounting from an Excel number format code, see [excel](crate::excel) for the
+    /// supported tokens.
+    ///
+    /// With one section negative values get a leading `-`, with two sections zero uses the
+    /// first section, as in Excel. Separators are always `,` and `.`, Excel takes them from the locale.
+    /// The rounding mode is [RoundingMode::HalfUp], as Excel rounds half away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, ExcelFormatError};
+    /// let ac = Accounting::from_excel_format("[$€-407] #,##0.00").unwrap();
+    /// assert_eq!(ac.format_money(-1234.5), "-€ 1,234.50");
+    ///
+    /// let ac = Accounting::from_excel_format("0;(0)").unwrap();
+    /// assert_eq!(ac.format_money(-1234.5), "(1235)");
+    /// assert_eq!(ac.format_money(2.5), "3");
+    ///
+    /// let err = Accounting::from_excel_format("0.00%").err();
+    /// assert_eq!(err, Some(ExcelFormatError::Unsupported("%".to_string())));
+    /// ```
+    pub fn from_excel_format(code: &str) -> Result<Self, ExcelFormatError> {
+        let sections = split_sections(code);
+        if sections.len() > 4 {
+            return Err(ExcelFormatError::TooManySections);
+        }
+        let positive = parse_section(sections[0], false)?;
+        let negative = sections.get(1).map(|s| parse_section(s, false)).transpose()?;
+        let zero = sections.get(2).map(|s| parse_section(s, true)).transpose()?;
+
+        // Sections of the same format code share the precision.
+        let (min, precision) = positive.precision.unwrap_or((0, 0));
+        for section in negative.iter().chain(zero.iter()) {
+            if section.precision.is_some_and(|p| p != (min, precision)) {
+                return Err(ExcelFormatError::Unsupported(code.to_string()));
+            }
+        }
+
+        // There is a single symbol, so every section must show the same one.
+        let mut symbols = std::iter::once(&positive).chain(negative.iter()).chain(zero.iter())
+            .filter_map(|s| s.symbol.as_deref());
+        let symbol = symbols.next();
+        if symbols.any(|s| Some(s) != symbol) {
+            return Err(ExcelFormatError::Unsupported(code.to_string()));
+        }
+
+        let mut ac = Accounting::new_from(symbol.unwrap_or("$"), precision);
+        ac.set_min_fraction_digits(min);
+        ac.set_rounding_mode(RoundingMode::HalfUp);
+        if !positive.grouping {
+            ac.set_grouping(Grouping::none());
+        }
+        // Templates built from sections only hold escaped braces and known placeholders.
+        match negative {
+            Some(negative) => {
+                ac.set_format_positive(&positive.template).unwrap();
+                ac.set_format_negative(&negative.template).unwrap();
+            }
+            None => ac.set_format(&positive.template).unwrap(),
+        }
+        ac.set_format_zero(&zero.map_or(positive.template, |zero| zero.template)).unwrap();
+        Ok(ac)
+    }
+
+    /// Returns the Excel number format code of the format strings, fraction digits and grouping.
+    ///
+    /// The minimum fraction digits are written as `0` and the others up to the maximum as `#`.
+    /// The symbol, code and name are written as quoted text. Excel only groups by three and
+    /// has no compact notation, so other groupings are written as `#,##0` and compact notation
+    /// is left out. `{sign}` is written as `-` in the negative section only. Sections that Excel
+    /// would derive are left out too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::Accounting;
+    /// let mut ac = Accounting::for_currency("EUR").unwrap();
+    /// assert_eq!(ac.to_excel_format(), r##""€"#,##0.00"##);
+    /// ac.set_format_negative("({s}{v})").unwrap();
+    /// ac.set_format_zero("{s} --").unwrap();
+    /// assert_eq!(ac.to_excel_format(), r##""€"#,##0.00;("€"#,##0.00);"€" --"##);
+    /// ```
+    pub fn to_excel_format(&self) -> String {
+        // `{sign}` is only written for negative values, so it is empty in the other sections.
+        let section = |tokens: &[Token], negative: bool| {
+            let mut result = String::new();
+            for token in tokens {
+                match token {
+                    Token::Literal(s) => write_literal(&mut result, s),
+                    Token::Symbol => write_literal(&mut result, &self.symbol),
+                    Token::Code => write_literal(&mut result, &self.code),
+                    Token::Name => write_literal(&mut result, &self.name),
+                    Token::Sign if negative => result.push('-'),
+                    Token::Sign => {}
+                    Token::Value => {
+                        result.push_str(if self.grouping.primary == 0 { "0" } else { "#,##0" });
+                        if self.precision > 0 {
+                            result.push('.');
+                            result.extend(std::iter::repeat_n('0', self.min_fraction));
+                            result.extend(std::iter::repeat_n('#', self.precision - self.min_fraction));
+                        }
+                    }
+                }
+            }
+            result
+        };
+        let positive = section(self.format_positive.tokens(), false);
+        let negative = section(self.format_negative.tokens(), true);
+        let zero = section(self.format_zero.tokens(), false);
+
+        if zero != positive {
+            format!("{};{};{}", positive, negative, zero)
+        } else if negative != format!("-{}", positive) {
+            format!("{};{}", positive, negative)
+        } else {
+            positive
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Accounting, ExcelFormatError};
+
+    #[test]
+    fn from_excel_format_test() {
+        let ac = Accounting::from_excel_format(r##"_("$"* #,##0.00_);_("$"* (#,##0.00);_("$"* "-"??_);_(@_)"##).unwrap();
+        assert_eq!(ac.format_money(1234.5), " $1,234.50 ");
+        assert_eq!(ac.format_money(-1234.5), " $(1,234.50)");
+        assert_eq!(ac.format_money(0), " $-   ");
+
+        let ac = Accounting::from_excel_format(r#"0.0\ "kr";-0.0\ "{kr}""#).unwrap();
+        assert_eq!(ac.format_money(-1234.56), "-1234.6 {kr}");
+        assert_eq!(ac.format_money(0), "0.0 kr");
+
+        let ac = Accounting::from_excel_format(r#""a;b"[Blue]#,##0;[Color10]-#,##0"#).unwrap();
+        assert_eq!(ac.format_money(-1000), "-1,000");
+        assert_eq!(ac.format_positive(), "a;b{v}");
+
+        let ac = Accounting::from_excel_format("0;(0)").unwrap();
+        assert_eq!(ac.format_money(-1234.5), "(1235)");
+        assert_eq!(ac.format_money(2.5), "3");
+        assert_eq!(ac.format_money(0.5), "1");
+
+        let ac = Accounting::from_excel_format("#,##0.0#;(#,##0.0#)").unwrap();
+        assert_eq!(ac.format_money(1234.5), "1,234.5");
+        assert_eq!(ac.format_money(-1234.567), "(1,234.57)");
+        assert_eq!(ac.format_money(0), "0.0");
+
+        let ac = Accounting::from_excel_format("[$€-407] 0;-[$€-407] 0;-").unwrap();
+        assert_eq!(ac.format_money(-5), "-€ 5");
+        let ac = Accounting::from_excel_format("0;-[$€-407] 0").unwrap();
+        assert_eq!(ac.format_money(5), "5");
+        assert_eq!(ac.format_money(-5), "-€ 5");
+    }
+
+    #[test]
+    fn from_excel_format_error_test() {
+        let error = |code: &str| Accounting::from_excel_format(code).err();
+        let unsupported = |s: &str| Some(ExcelFormatError::Unsupported(s.to_string()));
+        assert_eq!(error("\"$"), Some(ExcelFormatError::UnclosedQuote));
+        assert_eq!(error("[Red0"), Some(ExcelFormatError::UnclosedBracket));
+        assert_eq!(error("0;0;0;@;0"), Some(ExcelFormatError::TooManySections));
+        assert_eq!(error("#,##0,"), unsupported("#,##0,"));
+        assert_eq!(error("0.#0"), unsupported("0.#0"));
+        assert_eq!(error("[>100]0"), unsupported("[>100]"));
+        assert_eq!(error("0.00E+00"), unsupported("E"));
+        assert_eq!(error("0 0"), unsupported("0"));
+        assert_eq!(error("0.00;-0.0"), unsupported("0.00;-0.0"));
+        assert_eq!(error("[$€-407] 0;[$$-409] -0"), unsupported("[$€-407] 0;[$$-409] -0"));
+        assert_eq!(error("[$€] 0;-0;[$kr] 0"), unsupported("[$€] 0;-0;[$kr] 0"));
+        assert_eq!(error("[$€] 0 [$$]"), unsupported("[$$]"));
+    }
+
+    #[test]
+    fn to_excel_format_test() {
+        let mut ac = Accounting::new_from("kr", 0);
+        ac.set_format("{v} {s}").unwrap();
+        assert_eq!(ac.to_excel_format(), r#"#,##0 "kr""#);
+        ac.set_format_positive("{{{v}}} \"x\"").unwrap();
+        ac.set_grouping(crate::Grouping::none());
+        assert_eq!(ac.to_excel_format(), r#""{"0"}" \""x"\";-0 "kr";0 "kr""#);
+
+        let mut ac = Accounting::new_from("$", 2);
+        ac.set_format("{s}{sign}{v}").unwrap();
+        assert_eq!(ac.to_excel_format(), r##""$"#,##0.00;"$"-#,##0.00"##);
+        let copy = Accounting::from_excel_format(&ac.to_excel_format()).unwrap();
+        assert_eq!(copy.format_money(5), "$5.00");
+        assert_eq!(copy.format_money(-5), "$-5.00");
+        assert_eq!(copy.format_money(0), "$0.00");
+        ac.set_format_zero("{sign}--").unwrap();
+        assert_eq!(ac.to_excel_format(), r##""$"#,##0.00;"$"-#,##0.00;--"##);
+
+        let mut ac = Accounting::new_from("$", 3);
+        ac.set_min_fraction_digits(1);
+        assert_eq!(ac.to_excel_format(), r##""$"#,##0.0##"##);
+        let copy = Accounting::from_excel_format(&ac.to_excel_format()).unwrap();
+        assert_eq!(copy.format_money(1234.5), "$1,234.5");
+        assert_eq!(copy.format_money(-0.12345), "-$0.123");
+        assert_eq!(copy.to_excel_format(), ac.to_excel_format());
+        ac.set_min_fraction_digits(0);
+        assert_eq!(ac.to_excel_format(), r##""$"#,##0.###"##);
+
+        for code in [r##""$"#,##0.00;("$"#,##0.00);-"##, "[$€-407] #,##0.00", "0;(0)", r##""$"#,##0.00;"$"-#,##0.00"##, "0.0#;(0.0#)"] {
+            let ac = Accounting::from_excel_format(code).unwrap();
+            let copy = Accounting::from_excel_format(&ac.to_excel_format()).unwrap();
+            for value in [1234.5, -1234.5, 0.0] {
+                assert_eq!(copy.format_money(value), ac.format_money(value));
+            }
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! Exact decimal representation shared by every [FormatNumber](super::FormatNumber) implementation.
+
+use std::fmt;
+use std::str::FromStr;
+use super::rounding::{Remainder, RoundingMode};
+use super::NumberFormat;
+
+/// Maximum number of significant digits in [Digits], enough for every `i128`, `u128` and `f64`.
+pub(crate) const MAX_DIGITS: usize = 40;
+
+/// Exact decimal value `coefficient × 10^exponent` with a sign.
+///
+/// Every [FormatNumber](super::FormatNumber) implementation converts its value into `Digits`,
+/// so rounding, grouping and separators behave the same for all numeric types.
+///
+/// # Examples
+///
+/// ```
+/// # use accounting::{Digits, NumberFormat, RoundingMode};
+/// let x: Digits = "-1234.565".parse().unwrap();
+/// let format = NumberFormat::new(2, ",", ".");
+/// assert_eq!(x.round(2, RoundingMode::HalfUp).format(&format), "-1,234.57");
+/// assert_eq!(x.round(2, RoundingMode::HalfEven).format(&format), "-1,234.56");
+/// ```
+#[derive(Clone)]
+pub struct Digits {
+    negative: bool,
+    // ASCII digits without leading or trailing zeros, only the first `len` are used.
+    coefficient: [u8; MAX_DIGITS],
+    len: usize,
+    exponent: i32,
+    // Text used for NaN and infinite floats.
+    non_finite: Option<&'static str>,
+}
+
+impl PartialEq for Digits {
+    fn eq(&self, other: &Self) -> bool {
+        self.negative == other.negative
+            && self.coefficient() == other.coefficient()
+            && self.exponent == other.exponent
+            && self.non_finite == other.non_finite
+    }
+}
+
+impl Eq for Digits {}
+
+impl fmt::Debug for Digits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Digits")
+            .field("negative", &self.negative)
+            .field("coefficient", &std::str::from_utf8(self.coefficient()).unwrap())
+            .field("exponent", &self.exponent)
+            .field("non_finite", &self.non_finite)
+            .finish()
+    }
+}
+
+/// Error returned when parsing [Digits] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDigitsError;
+
+impl fmt::Display for ParseDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid decimal number")
+    }
+}
+
+impl std::error::Error for ParseDigitsError {}
+
+impl FromStr for Digits {
+    type Err = ParseDigitsError;
+
+    /// Parses a plain decimal string such as `-1234.50`, with at most 40 significant digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+            None => (unsigned, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseDigitsError);
+        }
+        if !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(ParseDigitsError);
+        }
+
+        let coefficient = integer.bytes().chain(fraction.bytes());
+        Digits::new(negative, coefficient, -(fraction.len() as i32)).ok_or(ParseDigitsError)
+    }
+}
+
+impl Digits {
+    /// Creates digits from ASCII digits, stripping leading and trailing zeros and the sign of zero.
+    /// Returns `None` if more than `MAX_DIGITS` significant digits remain.
+    fn new(negative: bool, digits: impl Iterator<Item = u8>, exponent: i32) -> Option<Self> {
+        let mut coefficient = [0; MAX_DIGITS];
+        let mut len = 0;
+        // Zeros are only stored once a non-zero digit follows them.
+        let mut zeros = 0;
+        for d in digits {
+            if d == b'0' {
+                zeros += (len > 0) as usize;
+                continue;
+            }
+            if len + zeros >= MAX_DIGITS {
+                return None;
+            }
+            coefficient[len..len + zeros].fill(b'0');
+            coefficient[len + zeros] = d;
+            len += zeros + 1;
+            zeros = 0;
+        }
+        let exponent = if len == 0 { 0 } else { exponent + zeros as i32 };
+        Some(Digits { negative: negative && len > 0, coefficient, len, exponent, non_finite: None })
+    }
+
+    /// Digits rendered as `text` regardless of the format, used for NaN and infinite floats.
+    pub(crate) fn non_finite(negative: bool, text: &'static str) -> Self {
+        Digits { negative, coefficient: [0; MAX_DIGITS], len: 0, exponent: 0, non_finite: Some(text) }
+    }
+
+    fn coefficient(&self) -> &[u8] {
+        &self.coefficient[..self.len]
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.len == 0 && self.non_finite.is_none()
+    }
+
+    /// Returns false for NaN and infinite floats.
+    pub fn is_finite(&self) -> bool {
+        self.non_finite.is_none()
+    }
+
+    /// Returns true if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the exponent of the most significant digit, `None` for zero, NaN and infinity.
+    pub(crate) fn magnitude(&self) -> Option<i32> {
+        (self.len > 0).then(|| self.len as i32 - 1 + self.exponent)
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> Digits {
+        Digits { negative: false, ..self.clone() }
+    }
+
+    /// Creates digits for `value × 10^exponent`.
+    pub(crate) fn from_i128(value: i128, exponent: i32) -> Digits {
+        Digits::from_u128(value < 0, value.unsigned_abs(), exponent)
+    }
+
+    /// Creates digits for `±value × 10^exponent`.
+    pub(crate) fn from_u128(negative: bool, mut value: u128, exponent: i32) -> Digits {
+        // `u128::MAX` has 39 digits.
+        let mut buffer = [0; 39];
+        let mut start = buffer.len();
+        loop {
+            start -= 1;
+            buffer[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        Digits::new(negative, buffer[start..].iter().copied(), exponent).unwrap()
+    }
+
+    /// Returns the signed coefficient and the exponent, if the coefficient fits an `i128`.
+    pub(crate) fn to_i128_parts(&self) -> Option<(i128, i32)> {
+        if self.non_finite.is_some() {
+            return None;
+        }
+        // Accumulate with the sign applied so `i128::MIN` is representable.
+        let sign = if self.negative { -1 } else { 1 };
+        let mut coefficient: i128 = 0;
+        for &d in self.coefficient() {
+            coefficient = coefficient.checked_mul(10)?.checked_add(sign * (d - b'0') as i128)?;
+        }
+        Some((coefficient, self.exponent))
+    }
+
+    /// Splits the absolute value into the integer part and the first `precision` fraction digits
+    /// as an integer, e.g. `12.34` into `(12, 340)` for precision 3. Further fraction digits are
+    /// dropped. Returns `None` for NaN, infinity or if a part does not fit an `u128`.
+    pub(crate) fn to_major_minor(&self, precision: usize) -> Option<(u128, u128)> {
+        if self.non_finite.is_some() {
+            return None;
+        }
+        // The digit at `10^place`, zero outside the coefficient.
+        let digit = |place: i64| {
+            let j = place - self.exponent as i64;
+            if j < 0 || j >= self.len as i64 { 0 } else { (self.coefficient[self.len - 1 - j as usize] - b'0') as u128 }
+        };
+        let accumulate = |n: Option<u128>, place| n?.checked_mul(10)?.checked_add(digit(place));
+        let integer_len = self.len as i64 + self.exponent as i64;
+        let major = (0..integer_len.max(0)).rev().fold(Some(0), accumulate)?;
+        let minor = (1..=precision as i64).map(|place| -place).fold(Some(0), accumulate)?;
+        Some((major, minor))
+    }
+
+    /// Multiplies the value by `10^places`.
+    pub(crate) fn shift(mut self, places: i32) -> Digits {
+        if self.len > 0 {
+            self.exponent += places;
+        }
+        self
+    }
+
+    /// Rounds to `precision` fraction digits with the given rounding mode.
+    pub fn round(&self, precision: usize, mode: RoundingMode) -> Digits {
+        let target = -(precision as i64);
+        let exponent = self.exponent as i64;
+        if self.non_finite.is_some() || exponent >= target {
+            return self.clone();
+        }
+
+        let len = self.len;
+        let dropped = (target - exponent) as usize;
+        let mut kept = [0; MAX_DIGITS];
+        let (kept_len, remainder) = if dropped > len {
+            (0, Remainder::BelowHalf)
+        } else {
+            let (head, rest) = self.coefficient().split_at(len - dropped);
+            kept[..head.len()].copy_from_slice(head);
+            let sticky = rest[1..].iter().any(|&d| d != b'0');
+            let remainder = match (rest[0], sticky) {
+                (b'0', false) => Remainder::Zero,
+                (b'5', false) => Remainder::Half,
+                (d, _) if d < b'5' => Remainder::BelowHalf,
+                _ => Remainder::AboveHalf,
+            };
+            (head.len(), remainder)
+        };
+
+        let kept = &mut kept[..kept_len];
+        let last_kept_odd = kept.last().is_some_and(|d| (d - b'0') % 2 == 1);
+        if mode.round_up(self.negative, last_kept_odd, remainder) && !increment(kept) {
+            // Every kept digit was a nine, the result is the next power of ten.
+            return Digits::new(self.negative, std::iter::once(b'1'), (target + kept_len as i64) as i32).unwrap();
+        }
+        Digits::new(self.negative, kept.iter().copied(), target as i32).unwrap()
+    }
+
+    /// Formats the digits with the separators and grouping of `format`,
+    /// padding the fraction to `format.precision` digits. No rounding is applied.
+    pub fn format(&self, format: &NumberFormat) -> String {
+        let mut result = String::new();
+        // Writing to a `String` never fails.
+        self.write(&mut result, format).unwrap();
+        result
+    }
+
+    /// Writes the digits like [Digits::format] without building an intermediate `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Digits, NumberFormat};
+    /// let x: Digits = "-1234.5".parse().unwrap();
+    /// let mut s = String::from("total: ");
+    /// x.write(&mut s, &NumberFormat::new(2, ",", ".")).unwrap();
+    /// assert_eq!(s, "total: -1,234.50");
+    /// ```
+    pub fn write<W: fmt::Write>(&self, w: &mut W, format: &NumberFormat) -> fmt::Result {
+        self.write_integer(w, format)?;
+        self.write_fraction(w, format)
+    }
+
+    /// Writes the sign and the grouped integer digits, or the text of NaN and infinite floats.
+    pub(crate) fn write_integer<W: fmt::Write>(&self, w: &mut W, format: &NumberFormat) -> fmt::Result {
+        if self.negative {
+            w.write_char('-')?;
+        }
+        if let Some(text) = self.non_finite {
+            return w.write_str(text);
+        }
+
+        let digits = self.coefficient();
+        let integer_len = (digits.len() as i64 + self.exponent as i64).max(0) as usize;
+        if integer_len == 0 {
+            w.write_char('0')
+        } else {
+            let integer = digits.iter().copied().chain(std::iter::repeat(b'0')).take(integer_len);
+            format.grouping.write(w, integer, integer_len, format.thousand)
+        }
+    }
+
+    /// Writes the decimal separator and the fraction digits padded to the precision, if any.
+    pub(crate) fn write_fraction<W: fmt::Write>(&self, w: &mut W, format: &NumberFormat) -> fmt::Result {
+        let digits = self.coefficient();
+        let fraction_len = (-(self.exponent as i64)).max(0) as usize;
+        if self.non_finite.is_some() || (format.precision == 0 && fraction_len == 0) {
+            return Ok(());
+        }
+
+        w.write_str(format.decimal)?;
+        let significant = fraction_len.min(digits.len());
+        for _ in significant..fraction_len {
+            w.write_char('0')?;
+        }
+        // The coefficient is ASCII digits only.
+        w.write_str(std::str::from_utf8(&digits[digits.len() - significant..]).unwrap())?;
+        for _ in fraction_len..format.precision {
+            w.write_char('0')?;
+        }
+        Ok(())
+    }
+}
+
+/// Adds one unit to the last digit, carrying over nines.
+/// Returns false if the carry runs past the first digit.
+fn increment(digits: &mut [u8]) -> bool {
+    for d in digits.iter_mut().rev() {
+        if *d == b'9' {
+            *d = b'0';
+        } else {
+            *d += 1;
+            return true;
+        }
+    }
+    false
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(s: &str, precision: usize, mode: RoundingMode) -> String {
+        let format = NumberFormat::new(precision, ",", ".");
+        s.parse::<Digits>().unwrap().round(precision, mode).format(&format)
+    }
+
+    #[test]
+    fn parse_test() {
+        let x: Digits = "-001234.5600".parse().unwrap();
+        assert!(x.is_negative());
+        assert_eq!(x.format(&NumberFormat::new(0, ",", ".")), "-1,234.56");
+        assert!("-0.000".parse::<Digits>().unwrap().is_zero());
+        assert!(!"-0.000".parse::<Digits>().unwrap().is_negative());
+        assert_eq!(".5".parse::<Digits>().unwrap().format(&NumberFormat::new(2, ",", ".")), "0.50");
+        assert_eq!("1.2.3".parse::<Digits>(), Err(ParseDigitsError));
+        assert_eq!("".parse::<Digits>(), Err(ParseDigitsError));
+        assert_eq!("-".parse::<Digits>(), Err(ParseDigitsError));
+        assert_eq!("1e5".parse::<Digits>(), Err(ParseDigitsError));
+        assert_eq!("1000.00".parse::<Digits>().unwrap(), Digits::from_i128(1, 3));
+    }
+
+    #[test]
+    fn capacity_test() {
+        let max = "9".repeat(MAX_DIGITS);
+        assert!(max.parse::<Digits>().is_ok());
+        assert!(format!("{}0000.0000", max).parse::<Digits>().is_ok());
+        assert!(format!("0.000{}", max).parse::<Digits>().is_ok());
+        assert_eq!(format!("1{}", max).parse::<Digits>(), Err(ParseDigitsError));
+        assert_eq!(format!("1.{}", max).parse::<Digits>(), Err(ParseDigitsError));
+
+        let x: Digits = max.parse().unwrap();
+        assert_eq!(x.shift(-1).round(0, RoundingMode::HalfUp), Digits::from_i128(1, MAX_DIGITS as i32 - 1));
+        assert_eq!(Digits::from_u128(false, u128::MAX, 0).to_i128_parts(), None);
+        assert_eq!(Digits::from_u128(true, 0, 5), Digits::from_i128(0, 0));
+    }
+
+    #[test]
+    fn write_test() {
+        let format = NumberFormat::new(2, ",", ".");
+        let mut s = String::new();
+        Digits::from_i128(-25, 2).write(&mut s, &format).unwrap();
+        s.push(' ');
+        Digits::from_i128(5, -4).write(&mut s, &format).unwrap();
+        s.push(' ');
+        Digits::non_finite(true, "inf").write(&mut s, &format).unwrap();
+        assert_eq!(s, "-2,500.00 0.0005 -inf");
+    }
+
+    #[test]
+    fn round_modes_test() {
+        use RoundingMode::*;
+        let cases = [
+            ("2.5", ["2", "3", "2", "2", "2", "3"]),
+            ("3.5", ["4", "4", "3", "3", "3", "4"]),
+            ("-2.5", ["-2", "-3", "-2", "-2", "-3", "-2"]),
+            ("2.4", ["2", "2", "2", "2", "2", "3"]),
+            ("-2.4", ["-2", "-2", "-2", "-2", "-3", "-2"]),
+            ("2.51", ["3", "3", "3", "2", "2", "3"]),
+            ("-0.4", ["0", "0", "0", "0", "-1", "0"]),
+            ("7", ["7", "7", "7", "7", "7", "7"]),
+        ];
+        let modes = [HalfEven, HalfUp, HalfDown, TowardZero, Floor, Ceiling];
+        for (value, expected) in cases.iter() {
+            for (mode, expected) in modes.iter().zip(expected.iter()) {
+                assert_eq!(round(value, 0, *mode), *expected, "{} {:?}", value, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn round_carry_test() {
+        assert_eq!(round("999.995", 2, RoundingMode::HalfUp), "1,000.00");
+        assert_eq!(round("0.0001", 2, RoundingMode::Ceiling), "0.01");
+        assert_eq!(round("-0.0001", 2, RoundingMode::Floor), "-0.01");
+        assert_eq!(round("0.0049", 2, RoundingMode::HalfUp), "0.00");
+        assert_eq!(round("0.005", 2, RoundingMode::HalfEven), "0.00");
+        assert_eq!(round("0.015", 2, RoundingMode::HalfEven), "0.02");
+        assert_eq!(round("1500", 0, RoundingMode::HalfEven), "1,500");
+    }
+
+    #[test]
+    fn i128_test() {
+        let x = Digits::from_i128(-12345, -2);
+        assert_eq!(x.format(&NumberFormat::new(2, ",", ".")), "-123.45");
+        assert_eq!(x.to_i128_parts(), Some((-12345, -2)));
+        assert_eq!(Digits::from_i128(1200, 0).to_i128_parts(), Some((12, 2)));
+        assert_eq!(Digits::from_i128(i128::MIN, 0).to_i128_parts(), Some((i128::MIN, 0)));
+        assert_eq!("170141183460469231731687303715884105728".parse::<Digits>().unwrap().to_i128_parts(), None);
+        assert_eq!(Digits::from_i128(0, -2).to_i128_parts(), Some((0, 0)));
+    }
+
+    #[test]
+    fn major_minor_test() {
+        let x: Digits = "-1234.5678".parse().unwrap();
+        assert_eq!(x.to_major_minor(2), Some((1234, 56)));
+        assert_eq!(x.to_major_minor(0), Some((1234, 0)));
+        assert_eq!(x.to_major_minor(6), Some((1234, 567800)));
+        assert_eq!(Digits::from_i128(5, 2).to_major_minor(2), Some((500, 0)));
+        assert_eq!(Digits::from_i128(5, -3).to_major_minor(2), Some((0, 0)));
+        assert_eq!(Digits::from_u128(false, u128::MAX, 0).to_major_minor(2), Some((u128::MAX, 0)));
+        assert_eq!(Digits::from_i128(1, 39).to_major_minor(2), None);
+        assert_eq!(Digits::non_finite(false, "NaN").to_major_minor(2), None);
+    }
+
+    #[test]
+    fn shift_test() {
+        let format = NumberFormat::new(0, ",", ".");
+        let x: Digits = "12.5".parse().unwrap();
+        assert_eq!(x.clone().shift(3).format(&format), "12,500");
+        assert_eq!(x.clone().shift(-3).format(&format), "0.0125");
+        assert!("0".parse::<Digits>().unwrap().shift(-5).is_zero());
+    }
+}
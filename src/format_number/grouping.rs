@@ -0,0 +1,125 @@
+//! Digit grouping strategies used when inserting thousand separators.
+
+use std::fmt;
+
+/// Describes how the integer digits of a number are split into groups.
+///
+/// | Field | Description | Western | Indian |
+/// | ------------------- | ------------- | ------------- | ------------- |
+/// | primary             | size of the group closest to the decimal separator, `0` disables grouping | 3 | 3 |
+/// | secondary           | size of every further group, `0` stops after the primary group | 3 | 2 |
+/// | min_grouping_digits | minimum digits left of the primary group before any separator is inserted | 1 | 1 |
+///
+/// # Examples
+///
+/// ```
+/// # use accounting::{FormatNumber, Grouping, NumberFormat};
+/// let mut format = NumberFormat::new(2, ",", ".");
+/// format.grouping = Grouping::indian();
+/// assert_eq!(123456789.format_number_with(&format), "12,34,56,789.00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grouping {
+    pub primary: usize,
+    pub secondary: usize,
+    pub min_grouping_digits: usize,
+}
+
+impl Default for Grouping {
+    /// Returns the western grouping, groups of three digits.
+    fn default() -> Self {
+        Self::western()
+    }
+}
+
+impl Grouping {
+    /// Create Grouping from primary group size, secondary group size and minimum grouping digits.
+    pub fn new(primary: usize, secondary: usize, min_grouping_digits: usize) -> Self {
+        Grouping { primary, secondary, min_grouping_digits }
+    }
+
+    /// Groups of three digits: `1,234,567`.
+    pub fn western() -> Self {
+        Self::new(3, 3, 1)
+    }
+
+    /// Indian lakh/crore grouping: `12,34,567`.
+    pub fn indian() -> Self {
+        Self::new(3, 2, 1)
+    }
+
+    /// No separators at all: `1234567`.
+    pub fn none() -> Self {
+        Self::new(0, 0, 1)
+    }
+
+    /// Writes the `len` ASCII `digits` with `separator` between the groups.
+    pub(crate) fn write<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        digits: impl Iterator<Item = u8>,
+        len: usize,
+        separator: &str,
+    ) -> fmt::Result {
+        let grouped = self.primary > 0 && len > self.primary && len >= self.primary + self.min_grouping_digits;
+        for (i, d) in digits.enumerate() {
+            if grouped && i > 0 && self.is_boundary(len - i) {
+                w.write_str(separator)?;
+            }
+            w.write_char(d as char)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if a separator goes before the digit with `remaining` digits left, itself included.
+    fn is_boundary(&self, remaining: usize) -> bool {
+        remaining == self.primary
+            || (self.secondary > 0 && remaining > self.primary && (remaining - self.primary).is_multiple_of(self.secondary))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Grouping;
+
+    impl Grouping {
+        fn group(&self, digits: &str, separator: &str) -> String {
+            let mut result = String::new();
+            self.write(&mut result, digits.bytes(), digits.len(), separator).unwrap();
+            result
+        }
+    }
+
+    #[test]
+    fn western_test() {
+        let g = Grouping::western();
+        assert_eq!(g.group("1", ","), "1");
+        assert_eq!(g.group("123", ","), "123");
+        assert_eq!(g.group("1234", ","), "1,234");
+        assert_eq!(g.group("1234567", ","), "1,234,567");
+        assert_eq!(g.group("123456789", "'"), "123'456'789");
+    }
+
+    #[test]
+    fn indian_test() {
+        let g = Grouping::indian();
+        assert_eq!(g.group("123", ","), "123");
+        assert_eq!(g.group("1234", ","), "1,234");
+        assert_eq!(g.group("123456", ","), "1,23,456");
+        assert_eq!(g.group("123456789", ","), "12,34,56,789");
+    }
+
+    #[test]
+    fn custom_test() {
+        assert_eq!(Grouping::none().group("1234567", ","), "1234567");
+        assert_eq!(Grouping::new(4, 4, 1).group("123456789", ","), "1,2345,6789");
+        assert_eq!(Grouping::new(3, 0, 1).group("123456789", ","), "123456,789");
+
+        let g = Grouping::new(3, 3, 2);
+        assert_eq!(g.group("1234", "."), "1234");
+        assert_eq!(g.group("12345", "."), "12.345");
+        assert_eq!(g.group("1234567", "."), "1.234.567");
+    }
+}
@@ -1,32 +1,95 @@
-//! Define and implement FormatNumber trait. 
-//! 
+//! Define and implement FormatNumber trait.
+//!
 //! This trait be used for formatting numberic types to string with custom precision and separators.
 //! Implemented types include:
 //! * primitive type: i8, u8, i16, u16 i32, u32 i64, u64, i128, u128, isize, usize, f32, f64.
 //! * decimal type: `rust_decimal::Decimal`
 //!
+//! Floats are formatted from their shortest round-trip decimal representation, 
+//! so `1.005` is rounded as `1.005` and not as its binary approximation `1.00499999999999989...`.
+//! The tie then goes to the even digit with the default [RoundingMode::HalfEven], giving `1.00`.
+//!
 //! # Examples
-//! 
+//!
 //! ```
 //! # use accounting::FormatNumber;
 //! let x = 123456789.213123f64;
 //! assert_eq!(x.format_number(2, ",", "."), "123,456,789.21");
-//! 
+//!
 //! #[cfg(feature="decimal")]
 //! {
 //!     use rust_decimal::Decimal;
 //!     let x = Decimal::new(12345678921, 2);
-//!     assert_eq!( x.format_number(2, ",", "."), "123,456,789.21"); 
+//!     assert_eq!( x.format_number(2, ",", "."), "123,456,789.21");
 //! }
 //! ```
 
+mod digits;
+mod grouping;
+mod rounding;
 mod primitive;
 #[cfg(feature = "decimal")]
 mod decimal;
 
+pub use digits::{Digits, ParseDigitsError};
+pub use grouping::Grouping;
+pub use rounding::RoundingMode;
+
+/// Settings used by [FormatNumber::format_number_with].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat<'a> {
+    pub precision: usize,
+    pub thousand: &'a str,
+    pub decimal: &'a str,
+    pub grouping: Grouping,
+    pub rounding: RoundingMode,
+}
+
+impl<'a> NumberFormat<'a> {
+    /// Create NumberFormat from precision、thousand separator、 decimal separator, 
+    /// default grouping and default rounding mode.
+    pub fn new(precision: usize, thousand: &'a str, decimal: &'a str) -> Self {
+        NumberFormat { 
+            precision, 
+            thousand, 
+            decimal, 
+            grouping: Grouping::default(), 
+            rounding: RoundingMode::default() 
+        }
+    }
+}
+
+mod sealed {
+    /// Keeps [FormatNumber](super::FormatNumber) implemented by this crate only.
+    pub trait Sealed {}
+
+    macro_rules! sealed {
+        ($($t: ty),*) => { $(impl Sealed for $t {})* };
+    }
+
+    sealed!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64);
+    #[cfg(feature = "decimal")]
+    impl Sealed for rust_decimal::Decimal {}
+}
+
 /// Trait for formatting numbers with custom precision and separators. 
-pub trait FormatNumber {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String;
+/// 
+/// Implementations only provide the exact decimal [Digits] of the value, 
+/// rounding and separators are applied the same way for every type.
+///
+/// The trait is sealed: it is implemented for the types listed in [format_number](self) only.
+pub trait FormatNumber: sealed::Sealed {
+    /// Returns the exact decimal digits of the value.
+    fn to_digits(&self) -> Digits;
+
+    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
+        self.format_number_with(&NumberFormat::new(precision, thousand, decimal))
+    }
+
+    /// Formats the number according to all settings of `format`, including grouping and rounding mode.
+    fn format_number_with(&self, format: &NumberFormat) -> String {
+        self.to_digits().round(format.precision, format.rounding).format(format)
+    }
 }
 
 
@@ -46,14 +109,116 @@ mod tests {
     fn format_number_float_test() {
         let x = 123456789.213123f64;
         assert_eq!(x.format_number(2, ",", "."), "123,456,789.21");
+
+        assert_eq!(0.1f64.format_number(20, ",", "."), "0.10000000000000000000");
+        assert_eq!(0.1f32.format_number(12, ",", "."), "0.100000000000");
+        assert_eq!(1e21f64.format_number(0, ",", "."), "1,000,000,000,000,000,000,000");
+        assert_eq!(1.5e-7f64.format_number(8, ",", "."), "0.00000015");
+        assert_eq!(f64::NAN.format_number(2, ",", "."), "NaN");
+        assert_eq!(f64::NEG_INFINITY.format_number(2, ",", "."), "-inf");
 	}
+
+    #[test]
+    fn format_number_float_ties_test() {
+        let mut format = NumberFormat::new(2, ",", ".");
+        format.rounding = RoundingMode::HalfUp;
+        assert_eq!(0.125f64.format_number_with(&format), "0.13");
+        assert_eq!(1.005f64.format_number_with(&format), "1.01");
+        assert_eq!(2.675f64.format_number_with(&format), "2.68");
+        assert_eq!((-1.005f64).format_number_with(&format), "-1.01");
+        assert_eq!(1.005f32.format_number_with(&format), "1.01");
+        assert_eq!(2.675f32.format_number_with(&format), "2.68");
+
+        format.rounding = RoundingMode::HalfEven;
+        assert_eq!(0.125f64.format_number_with(&format), "0.12");
+        assert_eq!(1.005f64.format_number_with(&format), "1.00");
+        assert_eq!(2.675f64.format_number_with(&format), "2.68");
+        assert_eq!(1.015f64.format_number_with(&format), "1.02");
+
+        format.rounding = RoundingMode::HalfDown;
+        assert_eq!(2.675f64.format_number_with(&format), "2.67");
+        assert_eq!(1.006f64.format_number_with(&format), "1.01");
+    }
     #[cfg(feature = "decimal")]
     #[test]
     fn format_number_decimal_test() {
         let x = rust_decimal::Decimal::new(12345678921, 2);
-        assert_eq!( x.format_number(2, ",", "."), "123,456,789.21");     
-        
+        assert_eq!( x.format_number(2, ",", "."), "123,456,789.21");
+
         let x = rust_decimal::Decimal::new(-123456789213, 3);
-        assert_eq!( x.format_number(2, ",", "."), "-123,456,789.21"); 
+        assert_eq!( x.format_number(2, ",", "."), "-123,456,789.21");
+
+        let x = rust_decimal::Decimal::new(5, 1);
+        assert_eq!( x.format_number(3, ",", "."), "0.500");
 	}
-}
\ No newline at end of file
+
+    #[test]
+    fn format_number_grouping_test() {
+        let mut format = NumberFormat::new(2, ",", ".");
+        format.grouping = Grouping::indian();
+        assert_eq!(123456789i64.format_number_with(&format), "12,34,56,789.00");
+        assert_eq!((-1234567i64).format_number_with(&format), "-12,34,567.00");
+        assert_eq!(123456789.5f64.format_number_with(&format), "12,34,56,789.50");
+        assert_eq!((-100000.0f32).format_number_with(&format), "-1,00,000.00");
+        assert_eq!(255u8.format_number_with(&format), "255.00");
+
+        #[cfg(feature = "decimal")]
+        {
+            let x = rust_decimal::Decimal::new(-12345678921, 2);
+            assert_eq!(x.format_number_with(&format), "-12,34,56,789.21");
+        }
+    }
+
+    #[test]
+    fn format_number_rounding_test() {
+        let modes = [
+            RoundingMode::HalfEven, RoundingMode::HalfUp, RoundingMode::HalfDown,
+            RoundingMode::TowardZero, RoundingMode::Floor, RoundingMode::Ceiling,
+        ];
+        // Values exactly representable as binary floats, so every type sees the same number.
+        let values = [2.5f64, -2.5, 3.5, 0.125, -0.375, 1.0625, 1234.5, -0.0625];
+        for mode in modes.iter() {
+            for &value in values.iter() {
+                for precision in 0..4 {
+                    let mut format = NumberFormat::new(precision, ",", ".");
+                    format.rounding = *mode;
+                    let expected = value.to_string().parse::<Digits>().unwrap()
+                        .round(precision, *mode).format(&format);
+                    assert_eq!(value.format_number_with(&format), expected);
+                    assert_eq!((value as f32).format_number_with(&format), expected);
+
+                    #[cfg(feature = "decimal")]
+                    {
+                        use std::str::FromStr;
+                        let x = rust_decimal::Decimal::from_str(&value.to_string()).unwrap();
+                        assert_eq!(x.format_number_with(&format), expected);
+                    }
+                }
+            }
+
+            let mut format = NumberFormat::new(0, ",", ".");
+            format.rounding = *mode;
+            assert_eq!((-25i32).format_number_with(&format), (-25f64).format_number_with(&format));
+            assert_eq!(25u64.format_number_with(&format), 25f32.format_number_with(&format));
+        }
+
+        let mut format = NumberFormat::new(0, ",", ".");
+        format.rounding = RoundingMode::HalfUp;
+        assert_eq!(2.5f64.format_number_with(&format), "3");
+        assert_eq!((-2.5f64).format_number_with(&format), "-3");
+        format.rounding = RoundingMode::Floor;
+        assert_eq!((-0.5f32).format_number_with(&format), "-1");
+        format.rounding = RoundingMode::HalfEven;
+        assert_eq!((-0.5f32).format_number_with(&format), "0");
+
+        #[cfg(feature = "decimal")]
+        {
+            format.rounding = RoundingMode::HalfUp;
+            let x = rust_decimal::Decimal::new(25, 1);
+            assert_eq!(x.format_number_with(&format), "3");
+            format.rounding = RoundingMode::TowardZero;
+            let x = rust_decimal::Decimal::new(-29, 1);
+            assert_eq!(x.format_number_with(&format), "-2");
+        }
+    }
+}
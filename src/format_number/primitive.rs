@@ -1,177 +1,133 @@
-
-use super::FormatNumber;
+use std::fmt::{self, Write};
+use super::{Digits, FormatNumber};
 
 macro_rules! format_number_int {
-    ($x: expr, $p: expr, $t: expr, $d: expr) => {
-        {
-            let mut x = $x;
-            let precision = $p;
-            let thousand = $t;
-            let decimal = $d;
-
-            let mut result: String = "".to_string();
-            let mut minus: bool = false;
-
-            if let Some(neg) = x.checked_neg() {
-                if neg > 0 {
-                    x = neg;
-                    minus = true;
-                }
-            }
-
-            while x>=1000  {
-                result = format!("{}{:03}{}", thousand, x%1000, result);
-                x /= 1000;
-            }
-            result = format!("{}{}", x, result);
+    (signed $x: expr) => {
+        Digits::from_i128($x as i128, 0)
+    };
+    (unsigned $x: expr) => {
+        Digits::from_u128(false, $x as u128, 0)
+    };
+}
 
-            if minus {
-                result = format!("-{}", result);
-            }
-        
-            if precision > 0 {
-                result = format!("{}{}{}", result, decimal, "0".repeat(precision))
-            } 
+/// Stack buffer for the `{:e}` output of a float, which is at most 24 bytes.
+struct FloatBuffer {
+    bytes: [u8; 32],
+    len: usize,
+}
 
-            result
-        }
-    };
+impl fmt::Write for FloatBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
 }
 
 
 macro_rules! format_number_float {
-    ($x: expr, $p: expr, $t: expr, $d: expr) => {
+    ($x: expr) => {
         {
             let x = $x;
-            let precision = $p;
-            let thousand = $t;
-            let decimal = $d;
-
-            let v:Vec<char> = format!("{0:.1$}", x, precision).chars().collect();
-    
-            let l;
-            if let Some(index) = v.iter().position(|&r| r == '.') {
-                l = index - 1;
+            if x.is_nan() {
+                Digits::non_finite(false, "NaN")
+            } else if x.is_infinite() {
+                Digits::non_finite(x < 0.0, "inf")
             } else {
-                l = v.len() - 1;
-            }
-            
-            let mut buffer = String::new();
-            let mut j = 0;
-            for i in (0..=l).rev() {
-                j += 1;
-                buffer.push(v[i]);
-                if j==3 && i>0 && !(i==1 && v[0] == '-') {
-                    buffer.push(',');
-                    j = 0;
-                }
-            }
-
-            let mut result: String = buffer.chars().rev().collect();
-            
-            if thousand != "," {
-                result = result.replace(",", thousand);
+                // `{:e}` prints the shortest digits that round-trip to the same float,
+                // which is what was typed rather than the binary approximation.
+                let mut buffer = FloatBuffer { bytes: [0; 32], len: 0 };
+                write!(buffer, "{:e}", x).unwrap();
+                let s = std::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap();
+                let (mantissa, exponent) = s.split_once('e').unwrap();
+                mantissa.parse::<Digits>().unwrap().shift(exponent.parse().unwrap())
             }
-
-            let mut extra: String = v[l+1..v.len()].into_iter().collect();
-            if decimal != "." {
-                extra = extra.replace(".", decimal);
-            }
-
-            format!("{}{}", result, extra)
         }
     }
     
 }
 
 impl FormatNumber for i8 {
-    fn format_number(&self, precision: usize, _: &str, decimal: &str) -> String {
-        if precision > 0 {
-            format!("{}{}{}", *self, decimal, "0".repeat(precision))
-        } else {
-            self.to_string()
-        }
+    fn to_digits(&self) -> Digits {
+        format_number_int!(signed *self)
     }
 }
 
 impl FormatNumber for u8 {
-    fn format_number(&self, precision: usize, _: &str, decimal: &str) -> String {
-        if precision > 0 {
-            format!("{}{}{}", *self, decimal, "0".repeat(precision))
-        } else {
-            self.to_string()
-        }
+    fn to_digits(&self) -> Digits {
+        format_number_int!(unsigned *self)
     }
 }
 
 impl FormatNumber for i16 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(signed *self)
     }
 }
 
 impl FormatNumber for i32 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(signed *self)
     }
 }
 
 impl FormatNumber for i64 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(signed *self)
     }
 }
 
 impl FormatNumber for i128 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(signed *self)
     }
 }
 
 impl FormatNumber for isize {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(signed *self)
     }
 }
 
 impl FormatNumber for u16 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(unsigned *self)
     }
 }
 
 impl FormatNumber for u32 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(unsigned *self)
     }
 }
 
 impl FormatNumber for u64 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(unsigned *self)
     }
 }
 
 impl FormatNumber for u128 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(unsigned *self)
     }
 }
 
 impl FormatNumber for usize {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_int!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_int!(unsigned *self)
     }
 }
 
 impl FormatNumber for f32 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_float!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_float!(*self)
     }
 }
 
 impl FormatNumber for f64 {
-    fn format_number(&self, precision: usize, thousand: &str, decimal: &str) -> String {
-        format_number_float!(*self, precision, thousand, decimal)
+    fn to_digits(&self) -> Digits {
+        format_number_float!(*self)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,59 @@
+//! Rounding rules applied when a number has more fraction digits than the precision.
+
+/// Rule used to drop the fraction digits beyond the precision.
+///
+/// | Mode | 2.5 | 3.5 | -2.5 | 2.4 | -2.4 |
+/// | ------------- | ---- | ---- | ---- | ---- | ---- |
+/// | HalfEven      | 2 | 4 | -2 | 2 | -2 |
+/// | HalfUp        | 3 | 4 | -3 | 2 | -2 |
+/// | HalfDown      | 2 | 3 | -2 | 2 | -2 |
+/// | TowardZero    | 2 | 3 | -2 | 2 | -2 |
+/// | Floor         | 2 | 3 | -3 | 2 | -3 |
+/// | Ceiling       | 3 | 4 | -2 | 3 | -2 |
+///
+/// `HalfUp` and `HalfDown` break ties away from and toward zero respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingMode {
+    /// Round to nearest, ties to the even neighbour (banker's rounding).
+    #[default]
+    HalfEven,
+    /// Round to nearest, ties away from zero.
+    HalfUp,
+    /// Round to nearest, ties toward zero.
+    HalfDown,
+    /// Truncate the dropped digits.
+    TowardZero,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceiling,
+}
+
+/// How the dropped digits compare to half of the last kept unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Remainder {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl RoundingMode {
+    /// Returns true if the kept digits must be incremented by one unit.
+    pub(crate) fn round_up(self, negative: bool, last_kept_odd: bool, remainder: Remainder) -> bool {
+        if remainder == Remainder::Zero {
+            return false;
+        }
+        match self {
+            RoundingMode::HalfEven => {
+                remainder == Remainder::AboveHalf || (remainder == Remainder::Half && last_kept_odd)
+            }
+            RoundingMode::HalfUp => remainder != Remainder::BelowHalf,
+            RoundingMode::HalfDown => remainder == Remainder::AboveHalf,
+            RoundingMode::TowardZero => false,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+        }
+    }
+}
@@ -0,0 +1,445 @@
+//! The `LC_MONETARY` section of POSIX locale definition files, like those under
+//! `/usr/share/i18n/locales` that `localedef` compiles.
+//!
+//! [Accounting::from_lc_monetary] formats like the C library of the host does for the locale:
+//! symbol, separators, grouping and fraction digits are taken as they are, and the position of
+//! the symbol and sign follow `p_cs_precedes`, `p_sep_by_space`, `p_sign_posn` and their `n_`
+//! counterparts.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Accounting;
+//! let source = r#"
+//! comment_char %
+//! escape_char /
+//! LC_MONETARY
+//! int_curr_symbol     "EUR "
+//! currency_symbol     "<U20AC>"
+//! mon_decimal_point   ","
+//! mon_thousands_sep   "."
+//! mon_grouping        3;3
+//! positive_sign       ""
+//! negative_sign       "-"
+//! frac_digits         2
+//! p_cs_precedes       0
+//! p_sep_by_space      1
+//! n_cs_precedes       0
+//! n_sep_by_space      1
+//! p_sign_posn         1
+//! n_sign_posn         1
+//! END LC_MONETARY
+//! "#;
+//! let ac = Accounting::from_lc_monetary(source).unwrap();
+//! assert_eq!(ac.format_money(-1234.567), "-1.234,57 €");
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::currency::Currency;
+use crate::{Accounting, Grouping};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LcMonetaryError {
+    /// There is no `LC_MONETARY` section, or it has no `END LC_MONETARY`.
+    MissingSection,
+    /// The section copies the section of the named locale, which has to be loaded instead.
+    Copy(String),
+    /// The value of the keyword is malformed.
+    InvalidValue(String),
+}
+
+impl fmt::Display for LcMonetaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LcMonetaryError::MissingSection => write!(f, "missing LC_MONETARY section"),
+            LcMonetaryError::Copy(ref locale) => write!(f, "LC_MONETARY copies locale {}", locale),
+            LcMonetaryError::InvalidValue(ref keyword) => write!(f, "invalid value of {}", keyword),
+        }
+    }
+}
+
+impl error::Error for LcMonetaryError {}
+
+/// The fields of an `LC_MONETARY` section, numbers missing from the section or set to `-1` are `None`.
+///
+/// `mon_grouping` holds the group sizes as written, starting at the decimal point. The last size
+/// repeats, and a `-1` stops grouping. Other keywords, like the `int_p_sep_by_space` variants
+/// used for ISO codes, are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LcMonetary {
+    pub int_curr_symbol: String,
+    pub currency_symbol: String,
+    pub mon_decimal_point: String,
+    pub mon_thousands_sep: String,
+    pub mon_grouping: Vec<i32>,
+    pub positive_sign: String,
+    pub negative_sign: String,
+    pub int_frac_digits: Option<u8>,
+    pub frac_digits: Option<u8>,
+    pub p_cs_precedes: Option<u8>,
+    pub p_sep_by_space: Option<u8>,
+    pub n_cs_precedes: Option<u8>,
+    pub n_sep_by_space: Option<u8>,
+    pub p_sign_posn: Option<u8>,
+    pub n_sign_posn: Option<u8>,
+}
+
+impl LcMonetary {
+    /// Parses the `LC_MONETARY` section of a locale definition file.
+    ///
+    /// The `comment_char` and `escape_char` directives are honoured, strings may use `<Uxxxx>`
+    /// for characters. Sections that copy another locale, like `copy "hi_IN"`, return
+    /// [LcMonetaryError::Copy] with the name of the locale to load instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{LcMonetary, LcMonetaryError};
+    /// let lc = LcMonetary::parse("LC_MONETARY\ncurrency_symbol \"<U20B9>\"\nmon_grouping 3;2\nEND LC_MONETARY").unwrap();
+    /// assert_eq!(lc.currency_symbol, "₹");
+    /// assert_eq!(lc.mon_grouping, [3, 2]);
+    ///
+    /// let err = LcMonetary::parse("LC_MONETARY\ncopy \"hi_IN\"\nEND LC_MONETARY").err();
+    /// assert_eq!(err, Some(LcMonetaryError::Copy("hi_IN".to_string())));
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, LcMonetaryError> {
+        let (mut comment, mut escape) = ('#', '\\');
+        let mut lines = Vec::new();
+        let mut continued = String::new();
+        for line in source.lines() {
+            let trimmed = line.trim();
+            let trimmed = strip_comment(trimmed, comment, escape).unwrap_or(trimmed);
+            if let Some(c) = directive(trimmed, "comment_char") {
+                comment = c;
+            } else if let Some(c) = directive(trimmed, "escape_char") {
+                escape = c;
+            } else if trimmed.starts_with(comment) {
+                continue;
+            } else if let Some(line) = trimmed.strip_suffix(escape).filter(|l| !l.ends_with(escape)) {
+                continued.push_str(line);
+            } else {
+                continued.push_str(trimmed);
+                lines.push(std::mem::take(&mut continued));
+            }
+        }
+
+        let start = lines.iter().position(|l| l == "LC_MONETARY").ok_or(LcMonetaryError::MissingSection)?;
+        let end = lines[start..]
+            .iter()
+            .position(|l| l.split_whitespace().eq(["END", "LC_MONETARY"]))
+            .ok_or(LcMonetaryError::MissingSection)?;
+
+        let mut lc = LcMonetary::default();
+        for line in &lines[start + 1..start + end] {
+            let (keyword, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let value = value.trim();
+            let invalid = || LcMonetaryError::InvalidValue(keyword.to_string());
+            let string = || decode(value, escape).ok_or_else(invalid);
+            let number = || match value.parse::<i32>() {
+                Ok(-1) => Ok(None),
+                Ok(n) => u8::try_from(n).map(Some).map_err(|_| invalid()),
+                Err(_) => Err(invalid()),
+            };
+            match keyword {
+                "copy" => return Err(LcMonetaryError::Copy(string()?)),
+                "int_curr_symbol" => lc.int_curr_symbol = string()?,
+                "currency_symbol" => lc.currency_symbol = string()?,
+                "mon_decimal_point" => lc.mon_decimal_point = string()?,
+                "mon_thousands_sep" => lc.mon_thousands_sep = string()?,
+                "positive_sign" => lc.positive_sign = string()?,
+                "negative_sign" => lc.negative_sign = string()?,
+                "mon_grouping" => {
+                    lc.mon_grouping = value
+                        .split(';')
+                        .filter(|n| !n.trim().is_empty())
+                        .map(|n| n.trim().parse::<i32>().map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?;
+                }
+                "int_frac_digits" => lc.int_frac_digits = number()?,
+                "frac_digits" => lc.frac_digits = number()?,
+                "p_cs_precedes" => lc.p_cs_precedes = number()?,
+                "p_sep_by_space" => lc.p_sep_by_space = number()?,
+                "n_cs_precedes" => lc.n_cs_precedes = number()?,
+                "n_sep_by_space" => lc.n_sep_by_space = number()?,
+                "p_sign_posn" => lc.p_sign_posn = number()?,
+                "n_sign_posn" => lc.n_sign_posn = number()?,
+                _ => {}
+            }
+        }
+        Ok(lc)
+    }
+
+    /// Returns the grouping of `mon_grouping`.
+    fn grouping(&self) -> Grouping {
+        let size = |i: usize| self.mon_grouping.get(i).copied();
+        match (size(0), size(1)) {
+            (Some(primary), _) if primary <= 0 => Grouping::none(),
+            (Some(primary), Some(secondary)) if secondary <= 0 => Grouping::new(primary as usize, 0, 1),
+            (Some(primary), Some(secondary)) => Grouping::new(primary as usize, secondary as usize, 1),
+            (Some(primary), None) => Grouping::new(primary as usize, primary as usize, 1),
+            (None, _) => Grouping::none(),
+        }
+    }
+}
+
+/// Returns the character of a `comment_char %` or `escape_char /` directive.
+fn directive(line: &str, name: &str) -> Option<char> {
+    let mut chars = line.strip_prefix(name)?.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Returns the line before a comment that follows a value, like `"<U202F>" % <NNBSP>`.
+fn strip_comment(line: &str, comment: char, escape: char) -> Option<&str> {
+    let mut quoted = false;
+    let mut chars = line.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            c if c == escape => {
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == comment && !quoted && i > 0 => return Some(line[..i].trim_end()),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Decodes a quoted string or a bare list of `<Uxxxx>` characters.
+fn decode(value: &str, escape: char) -> Option<String> {
+    let inner = match value.strip_prefix('"') {
+        Some(rest) => rest.strip_suffix('"')?,
+        None if value.starts_with('<') => value,
+        None => return None,
+    };
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == escape => result.push(chars.next()?),
+            '<' => {
+                let rest = chars.as_str();
+                let end = rest.find('>')?;
+                let code = u32::from_str_radix(rest[..end].strip_prefix('U')?, 16).ok()?;
+                result.push(char::from_u32(code)?);
+                chars = rest[end + 1..].chars();
+            }
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+/// Builds a format string from the POSIX `cs_precedes`, `sep_by_space` and `sign_posn` values.
+///
+/// Like glibc, `sep_by_space` 2 puts a space between the sign and the symbol when they are
+/// adjacent, and between the sign and the value when the sign precedes the symbol. An empty
+/// sign gets no space.
+fn template(cs_precedes: bool, sep_by_space: u8, sign_posn: u8, sign: &str) -> String {
+    let sign = sign.replace('{', "{{").replace('}', "}}");
+    // The space between the symbol and the value, and the space next to the sign.
+    let space = if sep_by_space == 1 { " " } else { "" };
+    let sign_space = if sep_by_space == 2 && !sign.is_empty() { " " } else { "" };
+    let symbol = "{s}";
+    match (cs_precedes, sign_posn) {
+        (true, 0) => format!("({}{}{{v}})", symbol, space),
+        (true, 1) => format!("{}{}{}{}{{v}}", sign, sign_space, symbol, space),
+        (true, 2) => format!("{}{}{{v}}{}", symbol, space, sign),
+        (true, 3) => format!("{}{}{}{}{{v}}", sign, sign_space, symbol, space),
+        (true, _) => format!("{}{}{}{}{{v}}", symbol, sign_space, sign, space),
+        (false, 0) => format!("({{v}}{}{})", space, symbol),
+        (false, 1) => format!("{}{{v}}{}{}", sign, space, symbol),
+        (false, 2) => format!("{{v}}{}{}{}{}", space, symbol, sign_space, sign),
+        (false, 3) => format!("{{v}}{}{}{}{}", space, sign, sign_space, symbol),
+        (false, _) => format!("{{v}}{}{}{}{}", space, symbol, sign_space, sign),
+    }
+}
+
+impl From<&LcMonetary> for Accounting {
+    /// Create Accounting from the fields of an `LC_MONETARY` section, missing numbers default
+    /// to the symbol before the value, no space and the sign first.
+    fn from(lc: &LcMonetary) -> Self {
+        let code = lc.int_curr_symbol.trim();
+        let decimal = if lc.mon_decimal_point.is_empty() { "." } else { &lc.mon_decimal_point };
+        let positive = template(
+            lc.p_cs_precedes.unwrap_or(1) == 1,
+            lc.p_sep_by_space.unwrap_or(0),
+            lc.p_sign_posn.unwrap_or(1),
+            &lc.positive_sign,
+        );
+        let negative = template(
+            lc.n_cs_precedes.unwrap_or(1) == 1,
+            lc.n_sep_by_space.unwrap_or(0),
+            lc.n_sign_posn.unwrap_or(1),
+            &lc.negative_sign,
+        );
+
+        // Templates are built from escaped signs and known placeholders.
+        let mut ac = Accounting::new(
+            &lc.currency_symbol,
+            lc.frac_digits.unwrap_or(0) as usize,
+            &lc.mon_thousands_sep,
+            decimal,
+            &positive,
+            &negative,
+            &positive,
+        );
+        ac.set_grouping(lc.grouping());
+        ac.set_code(code);
+        if let Some(currency) = Currency::from_code(code) {
+            ac.set_name(currency.name());
+        }
+        ac
+    }
+}
+
+impl Accounting {
+    /// Create Accounting from a locale definition file with an `LC_MONETARY` section,
+    /// see [LcMonetary::parse] and [lc_monetary](crate::lc_monetary).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::Accounting;
+    /// let source = "LC_MONETARY
+    /// int_curr_symbol \"CHF \"
+    /// currency_symbol \"CHF\"
+    /// mon_thousands_sep \"<U2019>\"
+    /// mon_grouping 3;3
+    /// negative_sign \"-\"
+    /// frac_digits 2
+    /// p_sep_by_space 1
+    /// n_sep_by_space 1
+    /// p_sign_posn 4
+    /// n_sign_posn 4
+    /// END LC_MONETARY";
+    /// let ac = Accounting::from_lc_monetary(source).unwrap();
+    /// assert_eq!(ac.format_money(-1234.5), "CHF- 1’234.50");
+    /// ```
+    pub fn from_lc_monetary(source: &str) -> Result<Self, LcMonetaryError> {
+        LcMonetary::parse(source).map(|lc| Accounting::from(&lc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{template, LcMonetary, LcMonetaryError};
+    use crate::Accounting;
+
+    fn load(locale: &str) -> Result<Accounting, LcMonetaryError> {
+        let path = format!("{}/tests/data/lc_monetary/{}", env!("CARGO_MANIFEST_DIR"), locale);
+        Accounting::from_lc_monetary(&std::fs::read_to_string(path).unwrap())
+    }
+
+    // Expected strings are the output of glibc `strfmon("%n")` for the locales the samples were taken from.
+    #[test]
+    fn locale_test() {
+        let cases = [
+            ("en_US", ["$1,234,567.89", "-$1,234,567.89", "$0.00", "-$12.50"]),
+            ("de_DE", ["1.234.567,89 €", "-1.234.567,89 €", "0,00 €", "-12,50 €"]),
+            ("de_CH", ["CHF 1’234’567.89", "CHF- 1’234’567.89", "CHF 0.00", "CHF- 12.50"]),
+            ("fr_FR", ["1\u{202f}234\u{202f}567,89 €", "-1\u{202f}234\u{202f}567,89 €", "0,00 €", "-12,50 €"]),
+            ("hi_IN", ["₹12,34,567.89", "-₹12,34,567.89", "₹0.00", "-₹12.50"]),
+            ("uk_UA", ["1\u{202f}234\u{202f}567,89грн.", "-1\u{202f}234\u{202f}567,89 грн.", "0,00грн.", "-12,50 грн."]),
+        ];
+        for (locale, expected) in cases {
+            let ac = load(locale).unwrap();
+            let actual = [1234567.891, -1234567.891, 0.0, -12.5].map(|v| ac.format_money(v));
+            assert_eq!(actual, expected, "{}", locale);
+        }
+        assert_eq!(load("en_IN").err(), Some(LcMonetaryError::Copy("hi_IN".to_string())));
+    }
+
+    // Expected templates are the output of glibc `strfmon("%n", -1234567.891)` with each combination.
+    #[test]
+    fn template_test() {
+        let cases = [
+            (false, 0, 0, "({v}{s})"),
+            (false, 0, 1, "-{v}{s}"),
+            (false, 0, 2, "{v}{s}-"),
+            (false, 0, 3, "{v}-{s}"),
+            (false, 0, 4, "{v}{s}-"),
+            (false, 1, 0, "({v} {s})"),
+            (false, 1, 1, "-{v} {s}"),
+            (false, 1, 2, "{v} {s}-"),
+            (false, 1, 3, "{v} -{s}"),
+            (false, 1, 4, "{v} {s}-"),
+            (false, 2, 0, "({v}{s})"),
+            (false, 2, 1, "-{v}{s}"),
+            (false, 2, 2, "{v}{s} -"),
+            (false, 2, 3, "{v}- {s}"),
+            (false, 2, 4, "{v}{s} -"),
+            (true, 0, 0, "({s}{v})"),
+            (true, 0, 1, "-{s}{v}"),
+            (true, 0, 2, "{s}{v}-"),
+            (true, 0, 3, "-{s}{v}"),
+            (true, 0, 4, "{s}-{v}"),
+            (true, 1, 0, "({s} {v})"),
+            (true, 1, 1, "-{s} {v}"),
+            (true, 1, 2, "{s} {v}-"),
+            (true, 1, 3, "-{s} {v}"),
+            (true, 1, 4, "{s}- {v}"),
+            (true, 2, 0, "({s}{v})"),
+            (true, 2, 1, "- {s}{v}"),
+            (true, 2, 2, "{s}{v}-"),
+            (true, 2, 3, "- {s}{v}"),
+            (true, 2, 4, "{s} -{v}"),
+        ];
+        for (cs_precedes, sep_by_space, sign_posn, expected) in cases {
+            let actual = template(cs_precedes, sep_by_space, sign_posn, "-");
+            assert_eq!(actual, expected, "{} {} {}", cs_precedes, sep_by_space, sign_posn);
+        }
+        // An empty sign gets no space, braces are escaped.
+        assert_eq!(template(true, 2, 1, ""), "{s}{v}");
+        assert_eq!(template(false, 2, 2, ""), "{v}{s}");
+        assert_eq!(template(true, 0, 1, "{"), "{{{s}{v}");
+    }
+
+    #[test]
+    fn parse_test() {
+        let source = r#"
+comment_char %
+escape_char /
+% Comment lines and comments after values are skipped.
+LC_MONETARY
+currency_symbol "<U0433><U0440><U043D>." % hr
+mon_decimal_point "/,"
+mon_thousands_sep <U0020>
+mon_grouping 3;/
+             2
+positive_sign "" % nothing
+negative_sign "%"
+frac_digits -1
+p_cs_precedes 0
+END LC_MONETARY
+"#;
+        let lc = LcMonetary::parse(source).unwrap();
+        assert_eq!(lc.currency_symbol, "грн.");
+        assert_eq!(lc.mon_decimal_point, ",");
+        assert_eq!(lc.mon_thousands_sep, " ");
+        assert_eq!(lc.mon_grouping, [3, 2]);
+        assert_eq!(lc.positive_sign, "");
+        assert_eq!(lc.negative_sign, "%");
+        assert_eq!(lc.frac_digits, None);
+        assert_eq!(lc.p_cs_precedes, Some(0));
+        assert_eq!(lc.n_cs_precedes, None);
+
+        let lc = LcMonetary::parse("LC_MONETARY\nmon_grouping 3;-1\nEND LC_MONETARY").unwrap();
+        assert_eq!(lc.mon_grouping, [3, -1]);
+        assert_eq!(lc.grouping(), crate::Grouping::new(3, 0, 1));
+        let lc = LcMonetary::parse("LC_MONETARY\nmon_grouping -1\nEND LC_MONETARY").unwrap();
+        assert_eq!(lc.grouping(), crate::Grouping::none());
+
+        let err = |s| LcMonetary::parse(s).err();
+        assert_eq!(err("LC_NUMERIC\nEND LC_NUMERIC"), Some(LcMonetaryError::MissingSection));
+        assert_eq!(err("LC_MONETARY\nfrac_digits 2"), Some(LcMonetaryError::MissingSection));
+        let invalid = |k: &str| Some(LcMonetaryError::InvalidValue(k.to_string()));
+        assert_eq!(err("LC_MONETARY\nfrac_digits two\nEND LC_MONETARY"), invalid("frac_digits"));
+        assert_eq!(err("LC_MONETARY\ncurrency_symbol \"$\nEND LC_MONETARY"), invalid("currency_symbol"));
+        assert_eq!(err("LC_MONETARY\ncurrency_symbol \"<UZZ>\"\nEND LC_MONETARY"), invalid("currency_symbol"));
+    }
+}
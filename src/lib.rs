@@ -6,23 +6,27 @@
 //! ```
 //! # use accounting::Accounting;
 //! let mut ac = Accounting::new_from("$", 2);
-//!	ac.set_format("{s} {v}");
-//!	assert_eq!(ac.format_money(1000000), "$ 1,000,000.00");
-//!	assert_eq!(ac.format_money(-5000), "-$ 5,000.00");
+//!    ac.set_format("{s} {v}").unwrap();
+//!    assert_eq!(ac.format_money(1000000), "$ 1,000,000.00");
+//!    assert_eq!(ac.format_money(-5000), "-$ 5,000.00");
 //! ```
 //! 
 //! Set the format string of [Accounting] variable，then format numbers as money values.  In the format string:  
 //! - {v} is placehoder of value, will be replaced by number.  
 //! - {s} is placehoder of symbol, will be replaced by currency symbol like $、￥ and so on.
+//! - {c} and {n} are placeholders of the currency code and name, {sign} places the minus sign.
+//! - {{ and }} are literal braces.
+//! 
+//! See [template] for all placeholders.
 //! 
 
 //! ```
 //! # use accounting::Accounting;
 //! #[cfg(feature="decimal")]
 //! fn format_decimal_type() {
-//! 	let mut ac = Accounting::new_from("$", 2);
-//! 	ac.set_format("{s} {v}");
-//! 	let x = rust_decimal::Decimal::new(-12345678921, 2);
+//!     let mut ac = Accounting::new_from("$", 2);
+//!     ac.set_format("{s} {v}").unwrap();
+//!     let x = rust_decimal::Decimal::new(-12345678921, 2);
 //!     assert_eq!(ac.format_money(x), "-$ 123,456,789.21"); 
 //! }
 //! ```
@@ -31,7 +35,43 @@
 
 pub mod unformat_money;
 pub mod format_number;
-pub use format_number::FormatNumber;
+pub mod currency;
+pub mod money;
+pub mod cldr;
+pub mod template;
+pub mod display;
+mod column;
+pub mod words;
+pub mod chinese;
+pub mod compact;
+pub mod percent;
+pub mod excel;
+pub mod pattern;
+pub mod strfmon;
+pub mod lc_monetary;
+pub mod accounting_js;
+pub mod config;
+#[cfg(feature = "serde")]
+pub mod money_string;
+pub use format_number::{Digits, FormatNumber, Grouping, NumberFormat, ParseDigitsError, RoundingMode};
+pub use cldr::LocaleError;
+pub use currency::{Currency, CurrencyError};
+pub use money::{Money, MoneyError};
+pub use template::TemplateError;
+pub use display::MoneyDisplay;
+pub use words::{AmountWords, MinorStyle, WordsError};
+pub use chinese::{format_chinese_uppercase, parse_chinese_uppercase, ChineseError};
+pub use compact::Compact;
+pub use percent::{Percent, PercentUnit};
+pub use excel::ExcelFormatError;
+pub use pattern::PatternError;
+pub use strfmon::StrfmonError;
+pub use lc_monetary::{LcMonetary, LcMonetaryError};
+pub use accounting_js::{AccountingJsFormat, AccountingJsSettings};
+pub use config::{AccountingBuilder, ConfigError};
+use std::fmt;
+use std::io;
+use template::{Placeholders, Template};
 pub use unformat_money::{unformat, UnformatError};
 
 /// Format numbers as money values according to settings.   
@@ -41,35 +81,58 @@ pub use unformat_money::{unformat, UnformatError};
 /// | Field | Type | Description | Default | Example |
 /// | --------------- | ------------- | ------------- | ------------- | ------------- |
 /// | symbol          | String | currency symbol |  $ | $ |
-/// | precision       | usize  | currency precision (decimal places) | 0 | 2 |
+/// | code            | String | ISO 4217 currency code | | USD |
+/// | name            | String | currency name | | US Dollar |
+/// | precision       | usize  | currency precision (decimal places), the maximum number of fraction digits | 0 | 2 |
+/// | min_fraction    | usize  | minimum number of fraction digits, trailing zeros beyond it are trimmed | precision | 0 |
 /// | thousand        | String | thousand separator | , | . |
 /// | decimal         | String | decimal separator | . | , |
-/// | format_positive | String | format string for positive values ({v} = value, {s} = symbol) | {s}{v} | {s} {v} |
+/// | format_positive | String | format string for positive values, see [template] for the placeholders | {s}{v} | {s} {v} |
 /// | format_negative | String | format string for negative values | -{s}{v} | {s} ({v}) |
 /// | format_zero     | String | format string for zero values | {s}{v} | {s} -- |
+/// | grouping        | [Grouping] | digit grouping of the integer part | [Grouping::western] | [Grouping::indian] |
+/// | rounding        | [RoundingMode] | rounding of digits beyond the precision | [RoundingMode::HalfEven] | [RoundingMode::HalfUp] |
+/// | compact         | Option<[Compact]> | compact notation like `$1.2K` | None | [Compact::short] |
 ///
+/// With feature `serde`, Accounting is serialized with these field names and the format strings
+/// as text, see [config].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "config::AccountingConfig", into = "config::AccountingConfig"))]
 pub struct Accounting {
 	symbol: String,
+	code: String,
+	name: String,
 	precision: usize,  
+	min_fraction: usize,
 	thousand: String,
 	decimal: String, 
-	format_positive: String,
-	format_negative: String,
-	format_zero: String
+	format_positive: Template,
+	format_negative: Template,
+	format_zero: Template,
+	grouping: Grouping,
+	rounding: RoundingMode,
+	compact: Option<Compact>
 }
 
 impl Default for Accounting {
     /// Returns a “default" Accounting.  
     fn default() -> Self {
-        let format = "{s}{v}";
+        let format = Template::parse("{s}{v}").unwrap();
         Accounting {
             symbol: "$".to_string(), 
+            code: String::new(),
+            name: String::new(),
             precision: 0, 
+            min_fraction: 0,
             thousand: ",".to_string(),
             decimal: ".".to_string(), 
-            format_positive: format.to_string(), 
-            format_negative: "-".to_string() + format, 
-            format_zero: format.to_string()
+            format_positive: format.clone(), 
+            format_negative: Template::parse("-{s}{v}").unwrap(), 
+            format_zero: format,
+            grouping: Grouping::default(),
+            rounding: RoundingMode::default(),
+            compact: None
         }
     }
 }
@@ -78,10 +141,12 @@ impl Accounting {
 
     /// Create Accounting from symbol、 precision and default settings.
     pub fn new_from(symbol: &str, precision: usize) -> Self {
-        let mut ac = Self::default();
-        ac.symbol = symbol.to_string();
-        ac.precision = precision;
-        return ac;
+        Accounting {
+            symbol: symbol.to_string(),
+            precision,
+            min_fraction: precision,
+            ..Self::default()
+        }
     }
     
     /// Create Accounting from symbol、 precision、thousand separator、 decimal separator and default settings.
@@ -94,15 +159,74 @@ impl Accounting {
     /// assert_eq!(ac.format_money(4999.99), "€4.999,99");
     /// ```
     pub fn new_from_seperator(symbol: &str, precision: usize, thousand: &str, decimal: &str) -> Self {
-        let mut ac = Self::default();
-        ac.symbol = symbol.to_string();
-        ac.precision = precision;
-        ac.thousand = thousand.to_string();
-        ac.decimal = decimal.to_string();
-        return ac;
+        Accounting {
+            symbol: symbol.to_string(),
+            precision,
+            min_fraction: precision,
+            thousand: thousand.to_string(),
+            decimal: decimal.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Create Accounting from the [Currency] registry: symbol, precision, separators and 
+    /// the position of the symbol come from the currency table.
+    /// 
+    /// Separators missing from the table default to `,` and `.`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::{Accounting, CurrencyError};
+    /// let ac = Accounting::for_currency("EUR").unwrap();
+    /// assert_eq!(ac.format_money(1234.56), "€1.234,56");
+    /// 
+    /// let ac = Accounting::for_currency("SEK").unwrap();
+    /// assert_eq!(ac.format_money(-1234.56), "-1 234,56 kr");
+    /// 
+    /// assert!(matches!(Accounting::for_currency("XYZ"), Err(CurrencyError::UnknownCode(_))));
+    /// ```
+    pub fn for_currency(code: &str) -> Result<Self, CurrencyError> {
+        let currency = Currency::try_from_code(code)?;
+
+        let (mut thousand, mut decimal) = (currency.thousands_separator(), currency.decimal_separator());
+        if decimal.is_empty() {
+            decimal = if thousand == "." { "," } else { "." };
+        }
+        if thousand.is_empty() {
+            thousand = if decimal == "," { "." } else { "," };
+        }
+
+        let space = currency.space_separator();
+        let space = if space.chars().all(char::is_whitespace) { space } else { "" };
+        let format = if currency.symbol_first() {
+            format!("{{s}}{}{{v}}", space)
+        } else {
+            format!("{{v}}{}{{s}}", space)
+        };
+
+        let mut ac = Self::new_from_seperator(currency.symbol(), currency.exponent() as usize, thousand, decimal);
+        ac.set_code(currency.code());
+        ac.set_name(currency.name());
+        // The space separator is whitespace only, so the template is always valid.
+        ac.set_format(&format).unwrap();
+        Ok(ac)
     }
 
     /// Create Accounting 
+    /// 
+    /// Malformed braces and unknown placeholders in the format strings are kept as literal
+    /// text. Use [Accounting::try_new] or [Accounting::builder] to get a [TemplateError] for
+    /// them instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let ac = Accounting::new("$", 2, ",", ".", "{s}{v}", "-{s}{v", "{s}{v}");
+    /// assert_eq!(ac.format_money(-5), "-${v");
+    /// assert_eq!(ac.format_negative(), "-{s}{{v");
+    /// ```
     pub fn new(
         symbol: &str, 
         precision: usize, 
@@ -112,17 +236,136 @@ impl Accounting {
         format_negative: &str, 
         format_zero: &str
     ) -> Self {
+        let parse = |format: &str| Template::parse(format).unwrap_or_else(|_| Template::parse_lenient(format));
+        Self::from_templates(symbol, precision, thousand, decimal, [parse(format), parse(format_negative), parse(format_zero)])
+    }
+
+    /// Create Accounting like [Accounting::new], but returns the error of a malformed
+    /// format string instead of keeping it as literal text.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::{Accounting, TemplateError};
+    /// let ac = Accounting::try_new("$", 2, ",", ".", "{s}{v}", "({s}{v})", "{s}--").unwrap();
+    /// assert_eq!(ac.format_money(-5), "($5.00)");
+    /// 
+    /// let err = Accounting::try_new("$", 2, ",", ".", "{s}{v}", "-{s}{v", "{s}{v}").err();
+    /// assert_eq!(err, Some(TemplateError::UnclosedBrace(4)));
+    /// ```
+    pub fn try_new(
+        symbol: &str, 
+        precision: usize, 
+        thousand: &str, 
+        decimal: &str, 
+        format: &str, 
+        format_negative: &str, 
+        format_zero: &str
+    ) -> Result<Self, TemplateError> {
+        let templates = [Template::parse(format)?, Template::parse(format_negative)?, Template::parse(format_zero)?];
+        Ok(Self::from_templates(symbol, precision, thousand, decimal, templates))
+    }
+
+    /// Create Accounting from the positive, negative and zero templates.
+    fn from_templates(symbol: &str, precision: usize, thousand: &str, decimal: &str, templates: [Template; 3]) -> Self {
+        let [format_positive, format_negative, format_zero] = templates;
         Accounting {
             symbol: symbol.to_string(), 
+            code: String::new(),
+            name: String::new(),
             precision, 
+            min_fraction: precision,
             thousand: thousand.to_string(), 
             decimal: decimal.to_string(), 
-            format_positive: format.to_string(), 
-            format_negative: format_negative.to_string(), 
-            format_zero: format_zero.to_string()
+            format_positive, 
+            format_negative, 
+            format_zero,
+            grouping: Grouping::default(),
+            rounding: RoundingMode::default(),
+            compact: None
         }
     }
 
+    /// Sets the currency code used by the `{c}` placeholder.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let mut ac = Accounting::new_from("$", 2);
+    /// ac.set_code("USD");
+    /// ac.set_format("{c} {v}").unwrap();
+    /// assert_eq!(ac.format_money(1000), "USD 1,000.00")
+    /// ```
+    pub fn set_code(&mut self, str: &str) {
+        self.code = str.to_string();
+    }
+
+    /// Sets the currency name used by the `{n}` placeholder.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let mut ac = Accounting::new_from("$", 2);
+    /// ac.set_name("US dollars");
+    /// ac.set_format("{v} {n}").unwrap();
+    /// assert_eq!(ac.format_money(1000), "1,000.00 US dollars")
+    /// ```
+    pub fn set_name(&mut self, str: &str) {
+        self.name = str.to_string();
+    }
+
+    /// Sets the minimum number of fraction digits. Trailing zeros beyond it are trimmed, and the
+    /// decimal separator is left out when no fraction digit remains.
+    /// 
+    /// The maximum, the precision, is raised to `digits` if it is lower.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let mut ac = Accounting::new_from("$", 4);
+    /// ac.set_min_fraction_digits(0);
+    /// assert_eq!(ac.format_money(0.0045), "$0.0045");
+    /// assert_eq!(ac.format_money(12), "$12");
+    /// assert_eq!(ac.format_money(12.5), "$12.5");
+    /// ac.set_min_fraction_digits(2);
+    /// assert_eq!(ac.format_money(12), "$12.00");
+    /// ```
+    pub fn set_min_fraction_digits(&mut self, digits: usize) {
+        self.min_fraction = digits;
+        self.precision = self.precision.max(digits);
+    }
+
+    /// Sets the maximum number of fraction digits, the precision values are rounded to.
+    /// 
+    /// The minimum is lowered to `digits` if it is higher.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let mut ac = Accounting::new_from("$", 2);
+    /// ac.set_max_fraction_digits(4);
+    /// assert_eq!(ac.format_money(0.004512), "$0.0045");
+    /// assert_eq!(ac.format_money(12), "$12.00");
+    /// ```
+    pub fn set_max_fraction_digits(&mut self, digits: usize) {
+        self.precision = digits;
+        self.min_fraction = self.min_fraction.min(digits);
+    }
+
+    /// Returns the minimum number of fraction digits.
+    pub fn min_fraction_digits(&self) -> usize {
+        self.min_fraction
+    }
+
+    /// Returns the maximum number of fraction digits, the precision.
+    pub fn max_fraction_digits(&self) -> usize {
+        self.precision
+    }
+
     /// Sets the separator for the thousands separation.
     /// 
     /// # Examples
@@ -144,30 +387,91 @@ impl Accounting {
     /// ```
     /// # use accounting::Accounting;
     /// let mut ac = Accounting::new_from("$", 2);
-	/// ac.set_decimal_separator("'");
-	/// assert_eq!(ac.format_money(123456789.213123), "$123,456,789'21")
+    /// ac.set_decimal_separator("'");
+    /// assert_eq!(ac.format_money(123456789.213123), "$123,456,789'21")
     /// ```
     pub fn set_decimal_separator(&mut self, str: &str) {
         self.decimal = str.to_string();
     }
 
+    /// Sets the digit grouping of the integer part.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::{Accounting, Grouping};
+    /// let mut ac = Accounting::new_from("₹", 2);
+    /// ac.set_grouping(Grouping::indian());
+    /// assert_eq!(ac.format_money(123456789), "₹12,34,56,789.00");
+    /// ```
+    pub fn set_grouping(&mut self, grouping: Grouping) {
+        self.grouping = grouping;
+    }
+
+    /// Sets the rounding mode used when a value has more fraction digits than the precision.
+    /// The default is banker's rounding, [RoundingMode::HalfEven], so ties like `1.005` and `2.5` go to the even digit.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::{Accounting, RoundingMode};
+    /// let mut ac = Accounting::new_from("$", 0);
+    /// assert_eq!(ac.format_money(2.5), "$2");
+    /// ac.set_rounding_mode(RoundingMode::HalfUp);
+    /// assert_eq!(ac.format_money(2.5), "$3");
+    /// ```
+    pub fn set_rounding_mode(&mut self, rounding: RoundingMode) {
+        self.rounding = rounding;
+    }
+
+    /// Sets the compact notation, `None` formats values in full.
+    /// 
+    /// Compact values use the format strings, separators and rounding mode of the Accounting,
+    /// but the significant digits of the [Compact] settings instead of the precision.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::{Accounting, Compact};
+    /// let mut ac = Accounting::new_from("$", 2);
+    /// ac.set_compact(Some(Compact::short()));
+    /// assert_eq!(ac.format_money(1234), "$1.2K");
+    /// assert_eq!(ac.format_money(-12e9), "-$12B");
+    /// assert_eq!(ac.format_money(999), "$999.00");
+    /// ```
+    pub fn set_compact(&mut self, compact: Option<Compact>) {
+        self.compact = compact;
+    }
+
     /// Sets the format string for positive and zero value. 
-    /// Also Sets format string by adding `-` at begining for negative value.
+    /// Also Sets format string by adding `-` at begining for negative value,
+    /// unless the format string places the sign itself with `{sign}`.
+    /// 
+    /// Returns an error and keeps the previous format if the format string is malformed.
     /// 
     /// # Examples
     /// 
     /// ```
     /// # use accounting::Accounting;
     /// let mut ac = Accounting::new_from("$", 2);
-    /// ac.set_format("{v} {s}");
-    ///	assert_eq!(ac.format_money(123456789.213123), "123,456,789.21 $");
-    ///	assert_eq!(ac.format_money(-123456789.213123), "-123,456,789.21 $");
-    ///	assert_eq!(ac.format_money(0), "0.00 $");
+    /// ac.set_format("{v} {s}").unwrap();
+    ///    assert_eq!(ac.format_money(123456789.213123), "123,456,789.21 $");
+    ///    assert_eq!(ac.format_money(-123456789.213123), "-123,456,789.21 $");
+    ///    assert_eq!(ac.format_money(0), "0.00 $");
+    /// 
+    /// ac.set_format("{v}{sign} {s}").unwrap();
+    /// assert_eq!(ac.format_money(-5), "5.00- $");
     /// ```
-    pub fn set_format(&mut self, str: &str) {
-        self.set_format_positive(str);
-        self.set_format_negative(&format!("-{}", str));
-        self.set_format_zero(str);
+    pub fn set_format(&mut self, str: &str) -> Result<(), TemplateError> {
+        let format = Template::parse(str)?;
+        self.format_negative = if format.has_sign() {
+            format.clone()
+        } else {
+            Template::parse(&format!("-{}", str))?
+        };
+        self.format_positive = format.clone();
+        self.format_zero = format;
+        Ok(())
     }
 
     /// Sets the format string for positive values.
@@ -177,47 +481,198 @@ impl Accounting {
     /// ```
     /// # use accounting::Accounting;
     /// let mut ac = Accounting::new_from("$", 2);
-    /// ac.set_format_positive("{s} {v}");
-    /// ac.set_format_negative("{s} ({v})");
-    ///	ac.set_format_zero( "{s} --");
+    /// ac.set_format_positive("{s} {v}").unwrap();
+    /// ac.set_format_negative("{s} ({v})").unwrap();
+    ///    ac.set_format_zero( "{s} --").unwrap();
     /// assert_eq!(ac.format_money(1000000), "$ 1,000,000.00");
-    ///	assert_eq!(ac.format_money(-5000), "$ (5,000.00)");
-    ///	assert_eq!(ac.format_money(0), "$ --");
+    ///    assert_eq!(ac.format_money(-5000), "$ (5,000.00)");
+    ///    assert_eq!(ac.format_money(0), "$ --");
     /// ```
-    pub fn set_format_positive(&mut self, str: &str) {
-        self.format_positive = str.to_string();
+    pub fn set_format_positive(&mut self, str: &str) -> Result<(), TemplateError> {
+        self.format_positive = Template::parse(str)?;
+        Ok(())
     }
 
     /// Sets the format string for negative values.
-    pub fn set_format_negative(&mut self, str: &str) {
-        self.format_negative = str.to_string();
+    pub fn set_format_negative(&mut self, str: &str) -> Result<(), TemplateError> {
+        self.format_negative = Template::parse(str)?;
+        Ok(())
     }
 
     /// Sets the format string for zero values.
-    pub fn set_format_zero(&mut self, str: &str) {
-        self.format_zero = str.to_string();
+    pub fn set_format_zero(&mut self, str: &str) -> Result<(), TemplateError> {
+        self.format_zero = Template::parse(str)?;
+        Ok(())
+    }
+
+    /// Returns the format string for positive values.
+    pub fn format_positive(&self) -> &str {
+        self.format_positive.as_str()
+    }
+
+    /// Returns the format string for negative values.
+    pub fn format_negative(&self) -> &str {
+        self.format_negative.as_str()
+    }
+
+    /// Returns the format string for zero values.
+    pub fn format_zero(&self) -> &str {
+        self.format_zero.as_str()
     }
  
     /// `format_money` function format numbers as money values, 
     /// using customisable settings of currency symbol, precision, and thousand/decimal separators. 
     /// The value type need to implement [FormatNumber] trait. 
     pub fn format_money<T:FormatNumber>(&self, value: T) -> String {
-        let mut number_string = value.format_number(self.precision, &self.thousand, &self.decimal);
-        let zero_string = 0.format_number(self.precision, &self.thousand, &self.decimal);
-
-        let format_string;
-        if &number_string[0..1] == "-" {
-            number_string = number_string[1..number_string.len()].to_string();
-            format_string = &self.format_negative;
-        } else if number_string == zero_string {
-            format_string = &self.format_zero;
+        let mut result = String::new();
+        // Writing to a `String` never fails.
+        self.write_money(&mut result, value).unwrap();
+        result
+    }
+
+    /// Writes the money value into `w`, like [Accounting::format_money] but without 
+    /// allocating a `String` per value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let ac = Accounting::new_from("$", 2);
+    /// let mut row = String::new();
+    /// for value in [1234.5, -5.0] {
+    ///     ac.write_money(&mut row, value).unwrap();
+    ///     row.push(';');
+    /// }
+    /// assert_eq!(row, "$1,234.50;-$5.00;");
+    /// ```
+    pub fn write_money<W: fmt::Write, T: FormatNumber>(&self, w: &mut W, value: T) -> fmt::Result {
+        self.write_rounded(w, &self.round(&value))
+    }
+
+    /// Returns a value that formats as money with `{}`, honouring width, fill and alignment.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// let ac = Accounting::new_from("$", 2);
+    /// assert_eq!(format!("[{:>12}]", ac.display(-1234.5)), "[  -$1,234.50]");
+    /// assert_eq!(format!("[{:*<10}]", ac.display(5)), "[$5.00*****]");
+    /// ```
+    pub fn display<T: FormatNumber>(&self, value: T) -> MoneyDisplay<'_, T> {
+        MoneyDisplay::new(self, value)
+    }
+
+    /// Returns the digits of `value` rounded to the precision, or scaled and rounded in compact mode.
+    pub(crate) fn round<T: FormatNumber>(&self, value: &T) -> Rounded<'_> {
+        self.round_digits(value.to_digits())
+    }
+
+    /// Rounds digits like [Accounting::round].
+    pub(crate) fn round_digits(&self, digits: Digits) -> Rounded<'_> {
+        let rounded = digits.round(self.precision, self.rounding);
+        let compact = match self.compact {
+            Some(ref compact) => compact,
+            None => return Rounded { digits: rounded, suffix: None },
+        };
+        // Values like 999.999 only reach the threshold once rounded to the precision.
+        match compact.apply(&digits, self.rounding).or_else(|| compact.apply(&rounded, self.rounding)) {
+            Some((digits, suffix)) => Rounded { digits, suffix: Some(suffix) },
+            None => Rounded { digits: rounded, suffix: None },
+        }
+    }
+
+    /// Writes a value rounded by [Accounting::round].
+    pub(crate) fn write_rounded<W: fmt::Write>(&self, w: &mut W, rounded: &Rounded) -> fmt::Result {
+        let value = rounded.digits.abs();
+        let format = self.value_format(rounded);
+        self.template(&rounded.digits).write(w, &self.placeholders(rounded, &value, &format))
+    }
+
+    /// Returns the number format of a rounded value, padded to the minimum fraction digits.
+    /// Compact values are not padded.
+    fn value_format(&self, rounded: &Rounded) -> NumberFormat<'_> {
+        let mut format = self.number_format();
+        format.precision = if rounded.suffix.is_some() { 0 } else { self.min_fraction };
+        format
+    }
+
+    /// Returns the format string for the sign of `digits`.
+    fn template(&self, digits: &Digits) -> &Template {
+        if digits.is_negative() {
+            &self.format_negative
+        } else if digits.is_zero() {
+            &self.format_zero
         } else {
-            format_string = &self.format_positive;
+            &self.format_positive
+        }
+    }
+
+    /// Returns the placeholder values for `rounded`, whose absolute value is `value`.
+    fn placeholders<'a>(&'a self, rounded: &Rounded<'a>, value: &'a Digits, format: &'a NumberFormat) -> Placeholders<'a> {
+        Placeholders {
+            symbol: &self.symbol,
+            code: &self.code,
+            name: &self.name,
+            negative: rounded.digits.is_negative(),
+            value,
+            suffix: rounded.suffix.unwrap_or(""),
+            format,
+        }
+    }
+
+    /// Writes the money value as UTF-8 into `w`, like [Accounting::write_money]. 
+    /// The value is written in several small pieces, so `w` should be buffered.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use accounting::Accounting;
+    /// use std::io::Write;
+    /// 
+    /// let ac = Accounting::new_from("€", 2);
+    /// let mut out = std::io::BufWriter::new(Vec::new());
+    /// ac.write_money_io(&mut out, 1234.5).unwrap();
+    /// out.write_all(b"\n").unwrap();
+    /// assert_eq!(out.into_inner().unwrap(), "€1,234.50\n".as_bytes());
+    /// ```
+    pub fn write_money_io<W: io::Write, T: FormatNumber>(&self, w: &mut W, value: T) -> io::Result<()> {
+        let mut adapter = IoAdapter { inner: w, error: Ok(()) };
+        match self.write_money(&mut adapter, value) {
+            Ok(()) => Ok(()),
+            Err(_) => adapter.error.and(Err(io::Error::other("formatter error"))),
         }
+    }
 
-        let mut result = format_string.replace("{s}", &self.symbol);
-        result = result.replace("{v}", &number_string);
-        return result;
+    fn number_format(&self) -> NumberFormat<'_> {
+        NumberFormat {
+            precision: self.precision,
+            thousand: &self.thousand,
+            decimal: &self.decimal,
+            grouping: self.grouping,
+            rounding: self.rounding,
+        }
+    }
+}
+
+/// A value rounded for formatting, with the suffix of its scale in compact mode.
+pub(crate) struct Rounded<'a> {
+    digits: Digits,
+    suffix: Option<&'a str>,
+}
+
+/// Adapts an [io::Write] to [fmt::Write], keeping the io error that stopped the writing.
+struct IoAdapter<'a, W> {
+    inner: &'a mut W,
+    error: io::Result<()>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Err(e);
+            fmt::Error
+        })
     }
 }
 
@@ -225,7 +680,7 @@ impl Accounting {
 #[cfg(test)]
 mod tests {
 
-    use super::Accounting;
+    use super::{Accounting, CurrencyError, Grouping, RoundingMode, TemplateError};
 
     #[test]
     fn test_number_type() {
@@ -286,24 +741,189 @@ mod tests {
     #[test]
     fn test_accounting_set() {
         let mut ac = Accounting::new_from("GBP", 0);
-        ac.set_format_positive("{s} {v}");
-        ac.set_format_negative("{s} ({v})");
-        ac.set_format_zero("{s} --");
+        ac.set_format_positive("{s} {v}").unwrap();
+        ac.set_format_negative("{s} ({v})").unwrap();
+        ac.set_format_zero("{s} --").unwrap();
         assert_eq!(ac.format_money(1000000), "GBP 1,000,000");
         assert_eq!(ac.format_money(-5000), "GBP (5,000)");
         assert_eq!(ac.format_money(0), "GBP --");
 
         let mut ac = Accounting::new_from("GBP", 2);
-        ac.set_format("{s} {v}");
-        ac.set_format_negative("{s} ({v})");
-        ac.set_format_zero( "{s} --");
+        ac.set_format("{s} {v}").unwrap();
+        ac.set_format_negative("{s} ({v})").unwrap();
+        ac.set_format_zero( "{s} --").unwrap();
         assert_eq!(ac.format_money(1000000), "GBP 1,000,000.00");
         assert_eq!(ac.format_money(-5000), "GBP (5,000.00)");
         assert_eq!(ac.format_money(0), "GBP --");
 
         let mut ac = Accounting::new_from("€", 2);
-        ac.set_format_zero("0.-");
+        ac.set_format_zero("0.-").unwrap();
         assert_eq!(ac.format_money(0), "0.-");
+
+        let mut ac = Accounting::new_from("₹", 2);
+        ac.set_grouping(Grouping::indian());
+        assert_eq!(ac.format_money(1234567.891), "₹12,34,567.89");
+        assert_eq!(ac.format_money(-100000), "-₹1,00,000.00");
+        assert_eq!(ac.format_money(999), "₹999.00");
+    }
+
+    #[test]
+    fn test_accounting_template() {
+        let mut ac = Accounting::new_from("{v}", 2);
+        ac.set_format("{{{s}}} {v}").unwrap();
+        assert_eq!(ac.format_money(5), "{{v}} 5.00");
+        assert_eq!(ac.format_money(-5), "-{{v}} 5.00");
+
+        assert_eq!(ac.set_format("{s} {v"), Err(TemplateError::UnclosedBrace(4)));
+        assert_eq!(ac.set_format_negative("{v}}"), Err(TemplateError::UnmatchedBrace(3)));
+        assert_eq!(ac.set_format_zero("{z}"), Err(TemplateError::UnknownPlaceholder("z".to_string())));
+        assert_eq!(ac.format_positive(), "{{{s}}} {v}");
+        assert_eq!(ac.format_negative(), "-{{{s}}} {v}");
+        assert_eq!(ac.format_zero(), "{{{s}}} {v}");
+    }
+
+    #[test]
+    fn test_accounting_placeholders() {
+        let mut ac = Accounting::for_currency("EUR").unwrap();
+        ac.set_format("{c}{nbsp}{v}").unwrap();
+        assert_eq!(ac.format_money(-1234.5), "-EUR\u{a0}1.234,50");
+        ac.set_format("{v} {n}").unwrap();
+        assert_eq!(ac.format_money(1234.5), "1.234,50 Euro");
+        ac.set_format("{s} {sign}{v}").unwrap();
+        assert_eq!(ac.format_money(-1234.5), "€ -1.234,50");
+        assert_eq!(ac.format_money(1234.5), "€ 1.234,50");
+        assert_eq!(ac.format_negative(), "{s} {sign}{v}");
+
+        ac.set_format_negative("{s}{v}{sign}{sign}").unwrap();
+        assert_eq!(ac.format_money(-1), "€1,00--");
+        ac.set_format_positive("{sign}{v}").unwrap();
+        assert_eq!(ac.format_money(1), "1,00");
+
+        let mut ac = Accounting::new_from("$", 2);
+        ac.set_format("[{c}{n}]{v}").unwrap();
+        assert_eq!(ac.format_money(1), "[]1.00");
+    }
+
+    #[test]
+    fn test_accounting_new_malformed() {
+        let ac = Accounting::new("$", 2, ",", ".", "{s}{v} {x}", "-{s}{v", "}{s}{v}");
+        assert_eq!(ac.format_money(5), "$5.00 {x}");
+        assert_eq!(ac.format_money(-5), "-${v");
+        assert_eq!(ac.format_money(0), "}$0.00");
+        assert_eq!(ac.format_positive(), "{s}{v} {{x}}");
+        // The rewritten format strings are valid.
+        let copy = Accounting::try_new("$", 2, ",", ".", ac.format_positive(), ac.format_negative(), ac.format_zero()).unwrap();
+        assert_eq!(copy, ac);
+    }
+
+    #[test]
+    fn test_accounting_try_new() {
+        let ac = Accounting::try_new("$", 2, ",", ".", "{s}{v}", "-{s}{v}", "{s}0").unwrap();
+        assert_eq!(ac.format_money(0), "$0");
+        let err = |f: &str| Accounting::try_new("$", 2, ",", ".", "{s}{v}", "-{s}{v}", f).err();
+        assert_eq!(err("{s}{v"), Some(TemplateError::UnclosedBrace(3)));
+        assert_eq!(err("{s}}"), Some(TemplateError::UnmatchedBrace(3)));
+        assert_eq!(err("{x}"), Some(TemplateError::UnknownPlaceholder("x".to_string())));
+    }
+
+    #[test]
+    fn test_accounting_write() {
+        let mut ac = Accounting::new_from("€", 2);
+        ac.set_format("{v}{nbsp}{s}").unwrap();
+        let mut s = String::new();
+        ac.write_money(&mut s, -1234.5).unwrap();
+        ac.write_money(&mut s, 0).unwrap();
+        assert_eq!(s, "-1,234.50\u{a0}€0.00\u{a0}€");
+
+        let mut out = Vec::new();
+        ac.write_money_io(&mut out, 1e20).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "100,000,000,000,000,000,000.00\u{a0}€");
+
+        let mut full = [0u8; 4];
+        let err = ac.write_money_io(&mut &mut full[..], 1234.5).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn test_accounting_for_currency() {
+        let ac = Accounting::for_currency("usd").unwrap();
+        assert_eq!(ac.format_money(1234.567), "$1,234.57");
+        assert_eq!(ac.format_money(-5), "-$5.00");
+
+        let ac = Accounting::for_currency("CHF").unwrap();
+        assert_eq!(ac.format_money(1234567.5), "CHF 1'234'567.50");
+
+        let ac = Accounting::for_currency("JPY").unwrap();
+        assert_eq!(ac.format_money(1234567.5), "¥1,234,568");
+
+        let ac = Accounting::for_currency("CZK").unwrap();
+        assert_eq!(ac.format_money(0), "0,00 Kč");
+
+        let ac = Accounting::for_currency("BHD").unwrap();
+        assert_eq!(ac.format_money(1.5), "د.ب 1.500");
+
+        // NGN has a bogus space separator in the table, and ALL has no separators.
+        let ac = Accounting::for_currency("NGN").unwrap();
+        assert_eq!(ac.format_money(1000), "₦1,000.00");
+        let ac = Accounting::for_currency("ALL").unwrap();
+        assert_eq!(ac.format_money(1000), "Lek1,000.00");
+
+        assert_eq!(Accounting::for_currency("").err(), Some(CurrencyError::UnknownCode("".to_string())));
+    }
+
+    #[test]
+    fn test_accounting_rounding() {
+        let mut ac = Accounting::new_from("$", 2);
+        ac.set_format_negative("({s}{v})").unwrap();
+        assert_eq!(ac.format_money(0.125), "$0.12");
+        assert_eq!(ac.format_money(-0.001), "$0.00");
+
+        ac.set_rounding_mode(RoundingMode::HalfUp);
+        assert_eq!(ac.format_money(0.125), "$0.13");
+        assert_eq!(ac.format_money(-0.125), "($0.13)");
+
+        ac.set_rounding_mode(RoundingMode::Floor);
+        assert_eq!(ac.format_money(-0.001), "($0.01)");
+        assert_eq!(ac.format_money(0.019), "$0.01");
+
+        ac.set_rounding_mode(RoundingMode::Ceiling);
+        assert_eq!(ac.format_money(0.001), "$0.01");
+        assert_eq!(ac.format_money(-0.019), "($0.01)");
+    }
+
+    #[test]
+    fn test_accounting_fraction_digits() {
+        let mut ac = Accounting::new_from("$", 2);
+        assert_eq!((ac.min_fraction_digits(), ac.max_fraction_digits()), (2, 2));
+        ac.set_max_fraction_digits(4);
+        ac.set_min_fraction_digits(1);
+        assert_eq!(ac.format_money(12u8), "$12.0");
+        assert_eq!(ac.format_money(-12i64), "-$12.0");
+        assert_eq!(ac.format_money(0.00451f64), "$0.0045");
+        assert_eq!(ac.format_money(1.25f32), "$1.25");
+        assert_eq!(ac.format_money(0.00004), "$0.0");
+        #[cfg(feature = "decimal")]
+        {
+            assert_eq!(ac.format_money(rust_decimal::Decimal::new(12500, 3)), "$12.5");
+            assert_eq!(ac.format_money(rust_decimal::Decimal::new(45, 4)), "$0.0045");
+        }
+
+        // No fraction digit left, no decimal separator.
+        ac.set_min_fraction_digits(0);
+        assert_eq!(ac.format_money(12u128), "$12");
+        assert_eq!(ac.format_money(11.99999), "$12");
+        assert_eq!(ac.format_money(0.0), "$0");
+        ac.set_compact(Some(crate::Compact::short()));
+        assert_eq!(ac.format_money(1500), "$1.5K");
+        ac.set_compact(None);
+
+        // The minimum raises the maximum and the maximum lowers the minimum.
+        ac.set_min_fraction_digits(3);
+        assert_eq!((ac.min_fraction_digits(), ac.max_fraction_digits()), (3, 4));
+        ac.set_max_fraction_digits(2);
+        assert_eq!((ac.min_fraction_digits(), ac.max_fraction_digits()), (2, 2));
+        ac.set_min_fraction_digits(6);
+        assert_eq!(ac.format_money(1), "$1.000000");
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,415 @@
+//! Money value type pairing an exact amount with its ISO 4217 currency.
+//!
+//! The amount is stored as an integer count of minor units (cents for `USD`), or with
+//! feature `decimal` as a `Decimal` scaled to the minor unit, so arithmetic never loses
+//! precision. Operations between different currencies return [MoneyError::CurrencyMismatch]
+//! instead of a wrong number, and [Accounting::format_money_checked] only formats money in
+//! the currency of the Accounting.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{Accounting, Money, MoneyError};
+//! let price = Money::from_minor(1999, "USD").unwrap();
+//! let shipping = Money::from_major(5, "USD").unwrap();
+//! let total = price.checked_add(shipping).unwrap();
+//! assert_eq!(total.minor_units(), 2499);
+//!
+//! let ac = Accounting::for_currency("USD").unwrap();
+//! assert_eq!(ac.format_money_checked(&total).unwrap(), "$24.99");
+//!
+//! let euros = Money::from_major(5, "EUR").unwrap();
+//! assert!(matches!(total.checked_add(euros), Err(MoneyError::CurrencyMismatch { .. })));
+//! ```
+
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+use crate::format_number::{Digits, FormatNumber, NumberFormat, RoundingMode};
+use crate::currency::Currency;
+use crate::Accounting;
+
+type Result<T> = std::result::Result<T, MoneyError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The currency code is not in the [Currency] registry.
+    UnknownCurrency(String),
+    /// An operation mixed two currencies.
+    CurrencyMismatch { left: String, right: String },
+    /// The result does not fit the amount type, `i128` minor units or with feature `decimal`
+    /// a `Decimal`.
+    Overflow,
+    /// The amount has more fraction digits than the currency's minor unit.
+    Inexact,
+    /// A NaN or infinite number was used.
+    NonFinite,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MoneyError::UnknownCurrency(ref code) => write!(f, "unknown currency code {}", code),
+            MoneyError::CurrencyMismatch { ref left, ref right } => {
+                write!(f, "currency mismatch: {} and {}", left, right)
+            }
+            MoneyError::Overflow => write!(f, "money amount overflow"),
+            MoneyError::Inexact => write!(f, "amount is finer than the currency minor unit"),
+            MoneyError::NonFinite => write!(f, "amount is not a finite number"),
+        }
+    }
+}
+
+impl error::Error for MoneyError {}
+
+/// An amount of money in a single currency.
+///
+/// `Money` compares equal only to money of the same currency. Ordering between
+/// different currencies is undefined, so `partial_cmp` returns `None` for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Amount,
+    code: [u8; 3],
+    exponent: u32,
+}
+
+impl Money {
+    /// Create Money from an amount of minor units and an ISO 4217 currency code.
+    pub fn from_minor(minor: i128, currency: &str) -> Result<Self> {
+        let (code, exponent) = lookup(currency)?;
+        Ok(Money { amount: to_amount(minor, exponent)?, code, exponent })
+    }
+
+    /// Create Money from a whole amount of major units and an ISO 4217 currency code.
+    pub fn from_major(major: i128, currency: &str) -> Result<Self> {
+        let (code, exponent) = lookup(currency)?;
+        let minor = major.checked_mul(pow10(exponent)?).ok_or(MoneyError::Overflow)?;
+        Ok(Money { amount: to_amount(minor, exponent)?, code, exponent })
+    }
+
+    /// Create Money from a `Decimal` amount of major units.
+    /// Fails with [MoneyError::Inexact] if the amount is finer than the minor unit.
+    #[cfg(feature = "decimal")]
+    pub fn from_decimal(amount: rust_decimal::Decimal, currency: &str) -> Result<Self> {
+        let (code, exponent) = lookup(currency)?;
+        let minor = to_minor(&amount.to_digits(), exponent)?;
+        Ok(Money { amount: to_amount(minor, exponent)?, code, exponent })
+    }
+
+    /// Returns the amount in major units as a `Decimal`. It is always `Some`, as the amount is
+    /// stored as a `Decimal` with feature `decimal`.
+    #[cfg(feature = "decimal")]
+    pub fn to_decimal(&self) -> Option<rust_decimal::Decimal> {
+        Some(self.amount)
+    }
+
+    /// Returns the amount in minor units.
+    pub fn minor_units(&self) -> i128 {
+        minor(&self.amount)
+    }
+
+    /// Returns the ISO 4217 currency code.
+    pub fn currency(&self) -> &str {
+        std::str::from_utf8(&self.code).unwrap()
+    }
+
+    /// Returns the number of decimal places of the minor unit.
+    pub fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    /// Returns true if the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.minor_units() == 0
+    }
+
+    /// Returns true if the amount is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.minor_units() < 0
+    }
+
+    /// Adds two amounts of the same currency.
+    pub fn checked_add(self, other: Money) -> Result<Money> {
+        self.same_currency(&other)?;
+        let minor = self.minor_units().checked_add(other.minor_units()).ok_or(MoneyError::Overflow)?;
+        self.with_minor(minor)
+    }
+
+    /// Subtracts an amount of the same currency.
+    pub fn checked_sub(self, other: Money) -> Result<Money> {
+        self.same_currency(&other)?;
+        let minor = self.minor_units().checked_sub(other.minor_units()).ok_or(MoneyError::Overflow)?;
+        self.with_minor(minor)
+    }
+
+    /// Negates the amount.
+    pub fn checked_neg(self) -> Result<Money> {
+        let minor = self.minor_units().checked_neg().ok_or(MoneyError::Overflow)?;
+        self.with_minor(minor)
+    }
+
+    /// Multiplies the amount by an integer.
+    pub fn checked_mul(self, factor: i128) -> Result<Money> {
+        let minor = self.minor_units().checked_mul(factor).ok_or(MoneyError::Overflow)?;
+        self.with_minor(minor)
+    }
+
+    /// Multiplies the amount by any number, rounding the result to the minor unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Money, RoundingMode};
+    /// let price = Money::from_minor(1999, "USD").unwrap();
+    /// let tax = price.checked_mul_round(0.0825, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(tax.minor_units(), 165);
+    /// ```
+    pub fn checked_mul_round<T: FormatNumber>(self, factor: T, rounding: RoundingMode) -> Result<Money> {
+        let factor = factor.to_digits();
+        if !factor.is_finite() {
+            return Err(MoneyError::NonFinite);
+        }
+        let (coefficient, exponent) = factor.to_i128_parts().ok_or(MoneyError::Overflow)?;
+        let product = self.minor_units().checked_mul(coefficient).ok_or(MoneyError::Overflow)?;
+        let rounded = Digits::from_i128(product, exponent).round(0, rounding);
+        self.with_minor(to_minor(&rounded, 0)?)
+    }
+
+    /// Compares two amounts of the same currency.
+    pub fn checked_cmp(&self, other: &Money) -> Result<Ordering> {
+        self.same_currency(other)?;
+        Ok(self.amount.cmp(&other.amount))
+    }
+
+    /// Returns Money in the same currency with another amount of minor units.
+    fn with_minor(self, minor: i128) -> Result<Money> {
+        Ok(Money { amount: to_amount(minor, self.exponent)?, ..self })
+    }
+
+    /// Returns the amount in major units.
+    fn to_digits(self) -> Digits {
+        Digits::from_i128(self.minor_units(), -(self.exponent as i32))
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<()> {
+        if self.code == other.code {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                left: self.currency().to_string(),
+                right: other.currency().to_string(),
+            })
+        }
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Money) -> Option<Ordering> {
+        self.checked_cmp(other).ok()
+    }
+}
+
+impl Accounting {
+    /// Formats money like [Accounting::format_money], but checks that the money is in the
+    /// currency of this Accounting, see [Accounting::set_code]. This is the only way to format
+    /// [Money], so the currency of the amount is never silently replaced.
+    ///
+    /// Fails with [MoneyError::CurrencyMismatch] if the codes differ, and with
+    /// [MoneyError::Inexact] if the precision is below the minor unit of the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Money, MoneyError};
+    /// let ac = Accounting::for_currency("USD").unwrap();
+    /// let dollars = Money::from_minor(123456, "USD").unwrap();
+    /// assert_eq!(ac.format_money_checked(&dollars).unwrap(), "$1,234.56");
+    ///
+    /// let yen = Money::from_major(1500, "JPY").unwrap();
+    /// let mismatch = MoneyError::CurrencyMismatch { left: "USD".to_string(), right: "JPY".to_string() };
+    /// assert_eq!(ac.format_money_checked(&yen), Err(mismatch));
+    /// ```
+    pub fn format_money_checked(&self, money: &Money) -> Result<String> {
+        if !self.code.eq_ignore_ascii_case(money.currency()) {
+            return Err(MoneyError::CurrencyMismatch {
+                left: self.code.clone(),
+                right: money.currency().to_string(),
+            });
+        }
+        if self.precision < money.exponent as usize {
+            return Err(MoneyError::Inexact);
+        }
+        let mut result = String::new();
+        // Writing to a `String` never fails.
+        self.write_rounded(&mut result, &self.round_digits(money.to_digits())).unwrap();
+        Ok(result)
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats as the plain amount followed by the currency code, e.g. `-1234.50 USD`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = NumberFormat {
+            grouping: crate::Grouping::none(),
+            ..NumberFormat::new(self.exponent as usize, "", ".")
+        };
+        write!(f, "{} {}", self.to_digits().format(&format), self.currency())
+    }
+}
+
+/// Returns the normalized code and minor unit exponent of a currency.
+fn lookup(currency: &str) -> Result<([u8; 3], u32)> {
+    let found = Currency::from_code(currency)
+        .ok_or_else(|| MoneyError::UnknownCurrency(currency.to_string()))?;
+    let code: [u8; 3] = found.code().as_bytes().try_into().unwrap();
+    Ok((code, found.exponent()))
+}
+
+/// The stored amount, see [Money].
+#[cfg(not(feature = "decimal"))]
+type Amount = i128;
+#[cfg(feature = "decimal")]
+type Amount = rust_decimal::Decimal;
+
+#[cfg(not(feature = "decimal"))]
+fn to_amount(minor: i128, _exponent: u32) -> Result<Amount> {
+    Ok(minor)
+}
+
+/// The scale is the exponent, so the mantissa is the count of minor units.
+#[cfg(feature = "decimal")]
+fn to_amount(minor: i128, exponent: u32) -> Result<Amount> {
+    rust_decimal::Decimal::try_from_i128_with_scale(minor, exponent).map_err(|_| MoneyError::Overflow)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn minor(amount: &Amount) -> i128 {
+    *amount
+}
+
+#[cfg(feature = "decimal")]
+fn minor(amount: &Amount) -> i128 {
+    amount.mantissa()
+}
+
+fn pow10(exponent: u32) -> Result<i128> {
+    10i128.checked_pow(exponent).ok_or(MoneyError::Overflow)
+}
+
+/// Converts digits in major units to an exact count of minor units.
+fn to_minor(digits: &Digits, exponent: u32) -> Result<i128> {
+    let (coefficient, digits_exponent) = digits.to_i128_parts().ok_or(MoneyError::Overflow)?;
+    let shift = digits_exponent as i64 + exponent as i64;
+    if shift < 0 {
+        return Err(MoneyError::Inexact);
+    }
+    let scale = 10i128.checked_pow(shift as u32).ok_or(MoneyError::Overflow)?;
+    coefficient.checked_mul(scale).ok_or(MoneyError::Overflow)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The largest amount of minor units that can be stored.
+    #[cfg(not(feature = "decimal"))]
+    const MAX_MINOR: i128 = i128::MAX;
+    #[cfg(feature = "decimal")]
+    const MAX_MINOR: i128 = (1 << 96) - 1;
+
+    #[test]
+    fn money_new_test() {
+        let m = Money::from_minor(-12345, "usd").unwrap();
+        assert_eq!(m.currency(), "USD");
+        assert_eq!(m.exponent(), 2);
+        assert_eq!(m.to_string(), "-123.45 USD");
+        assert_eq!(Money::from_minor(5, "JPY").unwrap().to_string(), "5 JPY");
+        assert_eq!(Money::from_major(1500, "JPY").unwrap().minor_units(), 1500);
+        assert_eq!(Money::from_major(3, "BHD").unwrap().minor_units(), 3000);
+        assert_eq!(Money::from_minor(1, "ZZZ"), Err(MoneyError::UnknownCurrency("ZZZ".to_string())));
+        assert_eq!(Money::from_minor(1, "US"), Err(MoneyError::UnknownCurrency("US".to_string())));
+        assert_eq!(Money::from_major(i128::MAX, "USD"), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn money_arithmetic_test() {
+        let a = Money::from_minor(1050, "EUR").unwrap();
+        let b = Money::from_minor(-250, "EUR").unwrap();
+        assert_eq!(a.checked_add(b).unwrap().minor_units(), 800);
+        assert_eq!(a.checked_sub(b).unwrap().minor_units(), 1300);
+        assert_eq!(b.checked_neg().unwrap().minor_units(), 250);
+        assert_eq!(a.checked_mul(-3).unwrap().minor_units(), -3150);
+
+        let max = Money::from_minor(MAX_MINOR, "EUR").unwrap();
+        assert_eq!(max.checked_add(a), Err(MoneyError::Overflow));
+        assert_eq!(max.checked_neg().unwrap().checked_add(b), Err(MoneyError::Overflow));
+        assert_eq!(max.checked_mul(2), Err(MoneyError::Overflow));
+        assert_eq!(max.checked_mul_round(1.5, RoundingMode::HalfEven), Err(MoneyError::Overflow));
+
+        let usd = Money::from_minor(1050, "USD").unwrap();
+        let mismatch = MoneyError::CurrencyMismatch { left: "EUR".to_string(), right: "USD".to_string() };
+        assert_eq!(a.checked_add(usd), Err(mismatch.clone()));
+        assert_eq!(a.checked_sub(usd), Err(mismatch.clone()));
+        assert_eq!(a.checked_cmp(&usd), Err(mismatch));
+    }
+
+    #[test]
+    fn money_mul_round_test() {
+        let a = Money::from_minor(1999, "USD").unwrap();
+        assert_eq!(a.checked_mul_round(0.5, RoundingMode::HalfEven).unwrap().minor_units(), 1000);
+        assert_eq!(a.checked_mul_round(0.5, RoundingMode::TowardZero).unwrap().minor_units(), 999);
+        assert_eq!(a.checked_mul_round(-0.5, RoundingMode::Floor).unwrap().minor_units(), -1000);
+        assert_eq!(a.checked_mul_round(3, RoundingMode::HalfEven).unwrap().minor_units(), 5997);
+        assert_eq!(a.checked_mul_round(1e40, RoundingMode::HalfEven), Err(MoneyError::Overflow));
+        assert_eq!(a.checked_mul_round(f64::NAN, RoundingMode::HalfEven), Err(MoneyError::NonFinite));
+    }
+
+    #[test]
+    fn format_money_checked_test() {
+        let usd = Accounting::for_currency("USD").unwrap();
+        let jpy = Money::from_major(1500, "JPY").unwrap();
+        let mismatch = MoneyError::CurrencyMismatch { left: "USD".to_string(), right: "JPY".to_string() };
+        assert_eq!(usd.format_money_checked(&jpy), Err(mismatch));
+        assert_eq!(usd.format_money_checked(&Money::from_minor(-5, "usd").unwrap()).unwrap(), "-$0.05");
+
+        // Without a code there is no currency to match.
+        let ac = Accounting::new_from("$", 2);
+        assert!(matches!(ac.format_money_checked(&jpy), Err(MoneyError::CurrencyMismatch { .. })));
+
+        let mut ac = Accounting::for_currency("BHD").unwrap();
+        ac.set_max_fraction_digits(2);
+        assert_eq!(ac.format_money_checked(&Money::from_minor(1005, "BHD").unwrap()), Err(MoneyError::Inexact));
+        ac.set_max_fraction_digits(4);
+        assert!(ac.format_money_checked(&Money::from_minor(1005, "BHD").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn money_cmp_test() {
+        let a = Money::from_minor(100, "GBP").unwrap();
+        let b = Money::from_minor(200, "GBP").unwrap();
+        let c = Money::from_minor(100, "USD").unwrap();
+        assert!(a < b);
+        assert_eq!(a.checked_cmp(&b), Ok(Ordering::Less));
+        assert_ne!(a, c);
+        assert_eq!(a.partial_cmp(&c), None);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn money_decimal_test() {
+        use rust_decimal::Decimal;
+        let m = Money::from_decimal(Decimal::new(-123450, 3), "USD").unwrap();
+        assert_eq!(m.minor_units(), -12345);
+        assert_eq!(m.to_decimal(), Some(Decimal::new(-12345, 2)));
+        assert_eq!(Money::from_decimal(Decimal::new(12345, 3), "USD"), Err(MoneyError::Inexact));
+        assert_eq!(Money::from_minor(MAX_MINOR, "USD").unwrap().to_decimal(), Some(Decimal::from_i128_with_scale(MAX_MINOR, 2)));
+        assert_eq!(Money::from_minor(MAX_MINOR + 1, "USD"), Err(MoneyError::Overflow));
+        assert_eq!(Money::from_minor(-MAX_MINOR - 1, "USD"), Err(MoneyError::Overflow));
+
+        // Amounts keep the scale of the minor unit.
+        let m = Money::from_minor(100, "USD").unwrap().checked_mul(3).unwrap();
+        assert_eq!(m.to_decimal().unwrap().to_string(), "3.00");
+        assert_eq!(m, Money::from_decimal(Decimal::new(3, 0), "USD").unwrap());
+    }
+}
@@ -0,0 +1,311 @@
+//! Serde helpers that write numbers as formatted money strings, like `"$1,234.50"`, and read
+//! them back. Requires feature `serde`.
+//!
+//! The Accounting comes from a [Profile] type, passed to [serialize] and [deserialize] with
+//! `serialize_with` and `deserialize_with`. Strings are read with the format strings and
+//! separators of the profile, into the exact digits parsed by the number type, so `i64`, `i128`
+//! and `Decimal` values come back unchanged. Parse failures, like a string that matches none of
+//! the format strings or a fraction for an integer type, are reported as serde errors.
+//! Compact values like `"$1.2K"` cannot be read back.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::OnceLock;
+//! use accounting::Accounting;
+//! use accounting::money_string::{self, Profile};
+//!
+//! struct Usd;
+//!
+//! impl Profile for Usd {
+//!     fn accounting() -> &'static Accounting {
+//!         static AC: OnceLock<Accounting> = OnceLock::new();
+//!         AC.get_or_init(|| Accounting::for_currency("USD").unwrap())
+//!     }
+//! }
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Item {
+//!     #[serde(serialize_with = "money_string::serialize::<Usd, _, _>")]
+//!     #[serde(deserialize_with = "money_string::deserialize::<Usd, _, _>")]
+//!     price: f64,
+//! }
+//!
+//! let json = serde_json::to_string(&Item { price: 1234.5 }).unwrap();
+//! assert_eq!(json, r#"{"price":"$1,234.50"}"#);
+//! let item: Item = serde_json::from_str(r#"{"price":"-$4,500.23"}"#).unwrap();
+//! assert_eq!(item.price, -4500.23);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+use crate::template::Token;
+use crate::{Accounting, FormatNumber};
+
+/// A named Accounting used by the helpers of [money_string](self).
+pub trait Profile {
+    /// Returns the Accounting of the profile, usually kept in a `static`.
+    fn accounting() -> &'static Accounting;
+}
+
+/// Serializes a number as the money string of the profile `P`.
+pub fn serialize<P, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    P: Profile,
+    T: FormatNumber,
+    S: Serializer,
+{
+    let ac = P::accounting();
+    let mut text = String::new();
+    // Writing to a `String` never fails.
+    ac.write_rounded(&mut text, &ac.round(value)).unwrap();
+    serializer.serialize_str(&text)
+}
+
+/// Deserializes a money string of the profile `P` into a number.
+pub fn deserialize<'de, P, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    P: Profile,
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    let text = String::deserialize(deserializer)?;
+    parse::<T>(P::accounting(), &text).map_err(D::Error::custom)
+}
+
+/// Reads a money string written with the format strings and separators of `ac`.
+fn parse<T>(ac: &Accounting, text: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let invalid = |e: &dyn fmt::Display| format!("invalid money string {:?}: {}", text, e);
+    // The negative format is tried first, it may be the positive one with `{sign}`.
+    let templates = [(&ac.format_negative, true), (&ac.format_positive, false), (&ac.format_zero, false)];
+    let (negative, value) = templates
+        .iter()
+        .find_map(|(template, negative)| {
+            let (sign, value) = read(ac, template.tokens(), text)?;
+            Some((*negative && (sign || !template.has_sign()), value))
+        })
+        .ok_or_else(|| invalid(&"no format string matches"))?;
+
+    let mut number = String::new();
+    if negative {
+        number.push('-');
+    }
+    let (integer, fraction) = match ac.decimal.is_empty() {
+        true => (value, ""),
+        false => value.split_once(ac.decimal.as_str()).unwrap_or((value, "")),
+    };
+    number.extend(integer.chars().filter(char::is_ascii_digit));
+    // Integer types do not parse a fraction of zeros.
+    if fraction.bytes().any(|b| b != b'0') {
+        number.push('.');
+        number.push_str(fraction);
+    }
+    number.parse().map_err(|e| invalid(&e))
+}
+
+/// Matches `text` against the tokens of a format string, returning whether a `{sign}` was
+/// found and the text of the value, `0` for a format string without `{v}`.
+fn read<'a>(ac: &Accounting, tokens: &[Token], text: &'a str) -> Option<(bool, &'a str)> {
+    let (mut rest, mut sign, mut value) = (text, false, "0");
+    for token in tokens {
+        let fixed = match token {
+            Token::Literal(s) => s.as_str(),
+            Token::Symbol => &ac.symbol,
+            Token::Code => &ac.code,
+            Token::Name => &ac.name,
+            Token::Sign => {
+                if let Some(r) = rest.strip_prefix('-') {
+                    (rest, sign) = (r, true);
+                }
+                continue;
+            }
+            Token::Value => {
+                let len = value_len(ac, rest);
+                if len == 0 {
+                    return None;
+                }
+                (value, rest) = rest.split_at(len);
+                continue;
+            }
+        };
+        rest = rest.strip_prefix(fixed)?;
+    }
+    rest.is_empty().then_some((sign, value))
+}
+
+/// Returns the length of the digits and separators at the start of `text`.
+fn value_len(ac: &Accounting, text: &str) -> usize {
+    let separator = |rest: &str, separator: &str| {
+        let next = rest.strip_prefix(separator).filter(|_| !separator.is_empty())?;
+        next.starts_with(|c: char| c.is_ascii_digit()).then_some(separator.len())
+    };
+    let (mut len, mut fraction) = (0, false);
+    loop {
+        let rest = &text[len..];
+        if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            len += 1;
+        } else if let Some(n) = separator(rest, &ac.thousand).filter(|_| len > 0 && !fraction) {
+            len += n;
+        } else if let Some(n) = separator(rest, &ac.decimal).filter(|_| !fraction) {
+            len += n;
+            fraction = true;
+        } else {
+            return len;
+        }
+    }
+}
+
+/// The helpers for `Option` fields, `None` is written as `null`.
+pub mod option {
+    use super::*;
+
+    /// Serializes an optional number as the money string of the profile `P`.
+    pub fn serialize<P, T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: Profile,
+        T: FormatNumber,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => super::serialize::<P, T, S>(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional money string of the profile `P` into a number.
+    pub fn deserialize<'de, P, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        P: Profile,
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(text) => parse::<T>(P::accounting(), &text).map(Some).map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+    use serde::{Deserialize, Serialize};
+    use crate::Accounting;
+    use super::Profile;
+
+    struct Eur;
+
+    impl Profile for Eur {
+        fn accounting() -> &'static Accounting {
+            static AC: OnceLock<Accounting> = OnceLock::new();
+            AC.get_or_init(|| {
+                let mut ac = Accounting::for_currency("EUR").unwrap();
+                ac.set_format("{v} {s}").unwrap();
+                ac.set_format_negative("({v} {s})").unwrap();
+                ac
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Line {
+        #[serde(serialize_with = "super::serialize::<Eur, _, _>")]
+        #[serde(deserialize_with = "super::deserialize::<Eur, _, _>")]
+        amount: i64,
+        #[serde(serialize_with = "super::option::serialize::<Eur, _, _>")]
+        #[serde(deserialize_with = "super::option::deserialize::<Eur, _, _>")]
+        fee: Option<f64>,
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let line = Line { amount: -1234, fee: Some(0.5) };
+        let json = serde_json::to_string(&line).unwrap();
+        assert_eq!(json, r#"{"amount":"(1.234,00 €)","fee":"0,50 €"}"#);
+        assert_eq!(serde_json::from_str::<Line>(&json).unwrap(), line);
+
+        let line = Line { amount: 7, fee: None };
+        let json = serde_json::to_string(&line).unwrap();
+        assert_eq!(json, r#"{"amount":"7,00 €","fee":null}"#);
+        assert_eq!(serde_json::from_str::<Line>(&json).unwrap(), line);
+    }
+
+    struct Dollar;
+
+    impl Profile for Dollar {
+        fn accounting() -> &'static Accounting {
+            static AC: OnceLock<Accounting> = OnceLock::new();
+            AC.get_or_init(|| {
+                let mut ac = Accounting::new_from("$", 2);
+                ac.set_format_negative("{s}{sign}{v}").unwrap();
+                ac.set_format_zero("{s} --").unwrap();
+                ac
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Exact {
+        #[serde(serialize_with = "super::serialize::<Dollar, _, _>")]
+        #[serde(deserialize_with = "super::deserialize::<Dollar, _, _>")]
+        small: i64,
+        #[serde(serialize_with = "super::serialize::<Dollar, _, _>")]
+        #[serde(deserialize_with = "super::deserialize::<Dollar, _, _>")]
+        large: i128,
+        #[serde(serialize_with = "super::serialize::<Dollar, _, _>")]
+        #[serde(deserialize_with = "super::deserialize::<Dollar, _, _>")]
+        float: f64,
+    }
+
+    #[test]
+    fn exact_test() {
+        // A profile without a code, with its own separators.
+        let value = Exact { small: 9007199254740993, large: -i128::MAX, float: 0.0 };
+        let json = serde_json::to_string(&value).unwrap();
+        assert!(json.starts_with(r#"{"small":"$9,007,199,254,740,993.00","large":"$-170,141,"#));
+        assert!(json.ends_with(r#""float":"$ --"}"#));
+        assert_eq!(serde_json::from_str::<Exact>(&json).unwrap(), value);
+
+        let value = Exact { small: 0, large: 12, float: -1.5 };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"small":"$ --","large":"$12.00","float":"$-1.50"}"#);
+        assert_eq!(serde_json::from_str::<Exact>(&json).unwrap(), value);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_test() {
+        use rust_decimal::Decimal;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Price {
+            #[serde(serialize_with = "super::serialize::<Eur, _, _>")]
+            #[serde(deserialize_with = "super::deserialize::<Eur, _, _>")]
+            value: Decimal,
+        }
+
+        let price = Price { value: Decimal::from_i128_with_scale(-7922816251426433759354395033, 2) };
+        let json = serde_json::to_string(&price).unwrap();
+        assert_eq!(json, r#"{"value":"(79.228.162.514.264.337.593.543.950,33 €)"}"#);
+        assert_eq!(serde_json::from_str::<Price>(&json).unwrap(), price);
+    }
+
+    #[test]
+    fn error_test() {
+        let error = |json: &str| serde_json::from_str::<Line>(json).unwrap_err().to_string();
+        assert!(error(r#"{"amount":"abc","fee":null}"#).starts_with("invalid money string \"abc\": no format string matches"));
+        assert!(error(r#"{"amount":"$5.00","fee":null}"#).starts_with("invalid money string \"$5.00\": no format string matches"));
+        // `i64` has no fraction digits.
+        assert!(error(r#"{"amount":"1,50 €","fee":null}"#).starts_with("invalid money string \"1,50 €\": invalid digit"));
+        assert!(error(r#"{"amount":5,"fee":null}"#).starts_with("invalid type: integer `5`, expected a string"));
+    }
+}
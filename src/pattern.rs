@@ -0,0 +1,394 @@
+//! ICU/CLDR decimal format patterns, like `¤#,##0.00;(¤#,##0.00)` or `#,##,##0.00 ¤`.
+//!
+//! In a pattern:
+//! - `¤` is the currency symbol, `¤¤` the ISO 4217 code and `¤¤¤` the currency name.
+//! - `#,##0.00` is the number, the last two groups between `,` set the primary and secondary
+//!   grouping sizes. The `0`s after `.` are the minimum fraction digits and the `0`s and `#`s
+//!   together the precision, like in `0.00##`.
+//! - `-` is the minus sign and `'text'` is literal text, `''` is a literal `'`. Letters are
+//!   literal text too, except an `E` right after the number, which starts an exponent.
+//! - An optional negative subpattern follows a `;`, only its prefix and suffix are used.
+//!
+//! Percent, per mille, scientific notation, significant digits, rounding increments and padding
+//! are reported as [PatternError::Unsupported].
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Accounting;
+//! let ac = Accounting::from_pattern("¤#,##0.00;(¤#,##0.00)", "USD").unwrap();
+//! assert_eq!(ac.format_money(-1234.5), "($1,234.50)");
+//!
+//! let ac = Accounting::from_pattern("#,##,##0.00 ¤¤", "INR").unwrap();
+//! assert_eq!(ac.format_money(1234567), "12,34,567.00 INR");
+//! assert_eq!(ac.to_pattern(), "#,##,##0.00 ¤¤");
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::currency::{Currency, CurrencyError};
+use crate::template::Token;
+use crate::{Accounting, Grouping};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// A `'` has no closing `'`.
+    UnclosedQuote,
+    /// A subpattern has no number, or its number is malformed, like `0#` or `#,##0.#0`.
+    InvalidNumber(String),
+    /// The pattern uses a feature Accounting does not support.
+    Unsupported(String),
+    /// The currency code is not in the [Currency] registry.
+    Currency(CurrencyError),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PatternError::UnclosedQuote => write!(f, "unclosed quote"),
+            PatternError::InvalidNumber(ref number) => write!(f, "invalid number pattern {}", number),
+            PatternError::Unsupported(ref token) => write!(f, "unsupported token {}", token),
+            PatternError::Currency(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for PatternError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            PatternError::Currency(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CurrencyError> for PatternError {
+    fn from(err: CurrencyError) -> PatternError {
+        PatternError::Currency(err)
+    }
+}
+
+// Characters with a meaning in patterns, quoted when written as literal text. `E` is only the
+// exponent marker right after the number, so it is quoted there only.
+const SPECIAL: &str = "0123456789#,.;%‰¤*@'";
+
+/// A parsed pattern, with the subpatterns turned into templates.
+pub(crate) struct Pattern {
+    pub positive: String,
+    pub negative: String,
+    /// The number of `0`s after the `.`.
+    pub min_fraction: usize,
+    /// The number of `0`s and `#`s after the `.`.
+    pub max_fraction: usize,
+    pub grouping: Grouping,
+}
+
+impl Pattern {
+    /// Parses a pattern, writing `minus` for the `-` of the affixes and in front of the
+    /// positive subpattern if there is no negative one.
+    pub(crate) fn parse(pattern: &str, minus: &str) -> Result<Self, PatternError> {
+        let (positive, negative) = split_subpatterns(pattern);
+        let (positive, number) = subpattern_template(positive, minus)?;
+        let negative = match negative {
+            Some(negative) => subpattern_template(negative, minus)?.0,
+            None => format!("{}{}", minus, positive),
+        };
+
+        let mut parts = number.splitn(2, '.');
+        let integer = parts.next().unwrap_or("");
+        let fraction = parts.next().unwrap_or("");
+        let invalid = || PatternError::InvalidNumber(number.to_string());
+        let zeros = fraction.chars().take_while(|&c| c == '0').count();
+        if integer.trim_start_matches(['#', ',']).contains(['#'])
+            || !fraction[zeros..].chars().all(|c| c == '#')
+        {
+            return Err(invalid());
+        }
+        let groups: Vec<usize> = integer.split(',').map(str::len).collect();
+        if groups[1..].contains(&0) {
+            return Err(invalid());
+        }
+        let grouping = match groups.len() {
+            1 => Grouping::none(),
+            2 => Grouping::new(groups[1], groups[1], 1),
+            n => Grouping::new(groups[n - 1], groups[n - 2], 1),
+        };
+
+        Ok(Pattern { positive, negative, min_fraction: zeros, max_fraction: fraction.len(), grouping })
+    }
+}
+
+/// Splits a pattern at the first `;` outside quotes.
+fn split_subpatterns(pattern: &str) -> (&str, Option<&str>) {
+    let mut quoted = false;
+    for (i, c) in pattern.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            ';' if !quoted => return (&pattern[..i], Some(&pattern[i + 1..])),
+            _ => {}
+        }
+    }
+    (pattern, None)
+}
+
+/// Turns a subpattern like `¤ #,##0.00` into a template and its number part.
+fn subpattern_template<'a>(subpattern: &'a str, minus: &str) -> Result<(String, &'a str), PatternError> {
+    let mut template = String::new();
+    let mut number = None;
+    let mut number_end = None;
+    let mut chars = subpattern.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            'E' if number_end == Some(i) => return Err(PatternError::Unsupported(c.to_string())),
+            '\'' if chars.next_if(|&(_, c)| c == '\'').is_some() => template.push('\''),
+            '\'' => loop {
+                match chars.next() {
+                    Some((_, '\'')) if chars.next_if(|&(_, c)| c == '\'').is_some() => template.push('\''),
+                    Some((_, '\'')) => break,
+                    Some((_, c)) => push_literal(&mut template, c),
+                    None => return Err(PatternError::UnclosedQuote),
+                }
+            },
+            '¤' => {
+                let mut count = 1;
+                while chars.next_if(|&(_, c)| c == '¤').is_some() {
+                    count += 1;
+                }
+                match count {
+                    1 => template.push_str("{s}"),
+                    2 => template.push_str("{c}"),
+                    3 => template.push_str("{n}"),
+                    _ => return Err(PatternError::Unsupported("¤".repeat(count))),
+                }
+            }
+            '-' => minus.chars().for_each(|c| push_literal(&mut template, c)),
+            '#' | '0' | ',' | '.' if number.is_none() => {
+                let mut end = i + 1;
+                while let Some((j, c)) = chars.next_if(|&(_, c)| "#0,.".contains(c)) {
+                    end = j + c.len_utf8();
+                }
+                number = Some(&subpattern[i..end]);
+                number_end = Some(end);
+                template.push_str("{v}");
+            }
+            c if SPECIAL.contains(c) => return Err(PatternError::Unsupported(c.to_string())),
+            c => push_literal(&mut template, c),
+        }
+    }
+    let number = number.ok_or_else(|| PatternError::InvalidNumber(subpattern.to_string()))?;
+    Ok((template, number))
+}
+
+fn push_literal(template: &mut String, c: char) {
+    match c {
+        '{' => template.push_str("{{"),
+        '}' => template.push_str("}}"),
+        c => template.push(c),
+    }
+}
+
+impl Accounting {
+    /// Create Accounting for a currency from an ICU/CLDR decimal format pattern, see
+    /// [pattern](crate::pattern) for the syntax.
+    ///
+    /// The symbol, code and name come from the [Currency] registry, the precision is the number
+    /// of fraction digits of the pattern and the minimum fraction digits the number of `0`s among
+    /// them. The separators are `,` and `.`, ICU takes them
+    /// from the locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, PatternError};
+    /// let ac = Accounting::from_pattern("'Total: '¤ #,##0.00", "EUR").unwrap();
+    /// assert_eq!(ac.format_money(-1234.5), "-Total: € 1,234.50");
+    ///
+    /// let ac = Accounting::from_pattern("¤¤¤ 0", "JPY").unwrap();
+    /// assert_eq!(ac.format_money(1234.5), "Yen 1234");
+    ///
+    /// let ac = Accounting::from_pattern("¤#,##0.00##", "USD").unwrap();
+    /// assert_eq!(ac.format_money(0.00451), "$0.0045");
+    /// assert_eq!(ac.format_money(12), "$12.00");
+    ///
+    /// let err = Accounting::from_pattern("#,##0%", "USD").err();
+    /// assert_eq!(err, Some(PatternError::Unsupported("%".to_string())));
+    /// ```
+    pub fn from_pattern(pattern: &str, currency: &str) -> Result<Self, PatternError> {
+        let currency = Currency::try_from_code(currency)?;
+        let pattern = Pattern::parse(pattern, "-")?;
+        let mut ac = Accounting::new(
+            currency.symbol(),
+            pattern.max_fraction,
+            ",",
+            ".",
+            &pattern.positive,
+            &pattern.negative,
+            &pattern.positive,
+        );
+        ac.set_min_fraction_digits(pattern.min_fraction);
+        ac.set_grouping(pattern.grouping);
+        ac.set_code(currency.code());
+        ac.set_name(currency.name());
+        Ok(ac)
+    }
+
+    /// Returns the ICU/CLDR decimal format pattern of the format strings, fraction digits and grouping.
+    ///
+    /// Patterns have no zero subpattern and no compact notation, so `format_zero` and the
+    /// compact setting are left out. `{sign}` is written as `-` in the negative subpattern only,
+    /// and the negative subpattern is left out if it is the positive one with a leading `-`.
+    /// A grouping that stops after the primary group cannot be written and is written as
+    /// repeating primary groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Grouping};
+    /// let mut ac = Accounting::for_currency("EUR").unwrap();
+    /// assert_eq!(ac.to_pattern(), "¤#,##0.00");
+    /// ac.set_grouping(Grouping::indian());
+    /// ac.set_format_negative("({v} {c})").unwrap();
+    /// assert_eq!(ac.to_pattern(), "¤#,##,##0.00;(#,##,##0.00 ¤¤)");
+    /// ```
+    pub fn to_pattern(&self) -> String {
+        let mut number = String::new();
+        let grouping = self.grouping;
+        if grouping.primary == 0 {
+            number.push('0');
+        } else {
+            number.push_str("#,");
+            if grouping.secondary != grouping.primary && grouping.secondary > 0 {
+                number.extend(std::iter::repeat_n('#', grouping.secondary));
+                number.push(',');
+            }
+            number.extend(std::iter::repeat_n('#', grouping.primary - 1));
+            number.push('0');
+        }
+        if self.precision > 0 {
+            number.push('.');
+            number.extend(std::iter::repeat_n('0', self.min_fraction));
+            number.extend(std::iter::repeat_n('#', self.precision - self.min_fraction));
+        }
+
+        let subpattern = |tokens: &[Token], negative: bool| {
+            let mut result = String::new();
+            for (i, token) in tokens.iter().enumerate() {
+                match token {
+                    Token::Literal(s) => {
+                        let after_number = i > 0 && tokens[i - 1] == Token::Value;
+                        write_literal(&mut result, s, after_number)
+                    }
+                    Token::Symbol => result.push('¤'),
+                    Token::Code => result.push_str("¤¤"),
+                    Token::Name => result.push_str("¤¤¤"),
+                    Token::Sign if negative => result.push('-'),
+                    Token::Sign => {}
+                    Token::Value => result.push_str(&number),
+                }
+            }
+            result
+        };
+        let positive = subpattern(self.format_positive.tokens(), false);
+        let negative = subpattern(self.format_negative.tokens(), true);
+        if negative == format!("-{}", positive) {
+            positive
+        } else {
+            format!("{};{}", positive, negative)
+        }
+    }
+}
+
+/// Writes template literal text, quoting the characters that have a meaning in patterns.
+/// A leading `E` is quoted right after the number, where it would be an exponent.
+fn write_literal(result: &mut String, s: &str, after_number: bool) {
+    let mut quoted = false;
+    for (i, c) in s.chars().enumerate() {
+        let special = (SPECIAL.contains(c) && c != '\'') || (c == 'E' && i == 0 && after_number);
+        if special != quoted {
+            result.push('\'');
+            quoted = special;
+        }
+        match c {
+            '\'' => result.push_str("''"),
+            c => result.push(c),
+        }
+    }
+    if quoted {
+        result.push('\'');
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Accounting, CurrencyError, Grouping, PatternError};
+    use super::Pattern;
+
+    #[test]
+    fn parse_test() {
+        let pattern = Pattern::parse("¤\u{a0}#,##0.00;¤-#,##0.00", "\u{2212}").unwrap();
+        assert_eq!(pattern.positive, "{s}\u{a0}{v}");
+        assert_eq!(pattern.negative, "{s}\u{2212}{v}");
+        assert_eq!(pattern.grouping, Grouping::western());
+
+        let pattern = Pattern::parse("#,##,##0.00## '{'¤''s", "-").unwrap();
+        assert_eq!(pattern.positive, "{v} {{{s}'s");
+        assert_eq!(pattern.negative, "-{v} {{{s}'s");
+        assert_eq!((pattern.min_fraction, pattern.max_fraction), (2, 4));
+        assert_eq!(pattern.grouping, Grouping::indian());
+
+        let pattern = Pattern::parse("'#'0 ¤;'''-'0", "-").unwrap();
+        assert_eq!(pattern.positive, "#{v} {s}");
+        assert_eq!(pattern.negative, "'-{v}");
+        assert_eq!(pattern.grouping, Grouping::none());
+
+        // `E` is literal text outside the number.
+        let pattern = Pattern::parse("#,##0.00 EUR;EUR -#,##0.00", "-").unwrap();
+        assert_eq!(pattern.positive, "{v} EUR");
+        assert_eq!(pattern.negative, "EUR -{v}");
+        assert_eq!(Pattern::parse("0'E'", "-").unwrap().positive, "{v}E");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = |pattern| Pattern::parse(pattern, "-").err();
+        let invalid = |s: &str| Some(PatternError::InvalidNumber(s.to_string()));
+        assert_eq!(error("'¤ 0"), Some(PatternError::UnclosedQuote));
+        assert_eq!(error("¤"), invalid("¤"));
+        assert_eq!(error("0;¤"), invalid("¤"));
+        assert_eq!(error("0#.00"), invalid("0#.00"));
+        assert_eq!(error("0.#0"), invalid("0.#0"));
+        assert_eq!(error("#,,##0"), invalid("#,,##0"));
+        assert_eq!(error("0 0"), Some(PatternError::Unsupported("0".to_string())));
+        assert_eq!(error("0.00E0"), Some(PatternError::Unsupported("E".to_string())));
+        assert_eq!(error("¤#,##0E+3"), Some(PatternError::Unsupported("E".to_string())));
+        assert_eq!(error("¤¤¤¤0"), Some(PatternError::Unsupported("¤¤¤¤".to_string())));
+        assert_eq!(
+            Accounting::from_pattern("0", "XYZ").err(),
+            Some(PatternError::Currency(CurrencyError::UnknownCode("XYZ".to_string())))
+        );
+    }
+
+    #[test]
+    fn to_pattern_test() {
+        let mut ac = Accounting::new_from("kr", 0);
+        ac.set_format("{v}{nbsp}{s}").unwrap();
+        ac.set_grouping(Grouping::none());
+        assert_eq!(ac.to_pattern(), "0\u{a0}¤");
+        ac.set_format_positive("{v} '#1' {n}").unwrap();
+        assert_eq!(ac.to_pattern(), "0 '''#1''' ¤¤¤;-0\u{a0}¤");
+
+        ac.set_format("{s}{sign}{v}").unwrap();
+        ac.set_grouping(Grouping::new(3, 0, 1));
+        assert_eq!(ac.to_pattern(), "¤#,##0;¤-#,##0");
+
+        ac.set_format("{v}Eur").unwrap();
+        assert_eq!(ac.to_pattern(), "#,##0'E'ur");
+        assert_eq!(Accounting::from_pattern(&ac.to_pattern(), "EUR").unwrap().format_money(5), "5Eur");
+
+        for pattern in ["¤#,##0.00;(¤#,##0.00)", "#,##,##0.00 ¤", "'#'0 ¤¤;(0)", "¤#,####0.0", "¤#,##0.00##", "0.## ¤", "#,##0.00 EUR"] {
+            let ac = Accounting::from_pattern(pattern, "USD").unwrap();
+            assert_eq!(ac.to_pattern(), pattern);
+        }
+    }
+}
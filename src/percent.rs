@@ -0,0 +1,275 @@
+//! Percent, per-mille and basis-point formatting, like `5.25 %`, `52.5‰` or `525 bp`.
+//!
+//! [Accounting::format_percent] formats a rate with the separators, grouping, rounding mode
+//! and format strings of the Accounting, so rates line up with the amounts next to them.
+//! The currency placeholders `{s}`, `{c}` and `{n}` and the spaces next to them are left out,
+//! and the percent sign is written next to the value. The fraction digits come from [Percent].
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{Accounting, Percent, PercentUnit};
+//! let mut ac = Accounting::for_currency("EUR").unwrap();
+//! ac.set_format_negative("({s}{v})").unwrap();
+//! let percent = Percent::for_locale("de", PercentUnit::Percent).unwrap();
+//! assert_eq!(ac.format_percent(0.0525, &percent), "5,25\u{a0}%");
+//! assert_eq!(ac.format_percent(-12.5, &percent), "(1.250\u{a0}%)");
+//!
+//! assert_eq!(ac.format_percent(0.0525, &Percent::new(PercentUnit::BasisPoint)), "525 bp");
+//! ```
+
+use std::fmt;
+use crate::format_number::FormatNumber;
+use crate::template::Placeholders;
+use crate::Accounting;
+
+/// The unit of a rate, with the power of ten values are multiplied by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PercentUnit {
+    /// Hundredths, `0.0525` is `5.25%`.
+    Percent,
+    /// Thousandths, `0.0525` is `52.5‰`.
+    PerMille,
+    /// Ten-thousandths, `0.0525` is `525 bp`.
+    BasisPoint,
+}
+
+impl PercentUnit {
+    /// Returns the power of ten of the unit.
+    pub fn exponent(&self) -> i32 {
+        match self {
+            PercentUnit::Percent => 2,
+            PercentUnit::PerMille => 3,
+            PercentUnit::BasisPoint => 4,
+        }
+    }
+
+    /// Returns the default symbol of the unit, `%`, `‰` or `bp`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PercentUnit::Percent => "%",
+            PercentUnit::PerMille => "‰",
+            PercentUnit::BasisPoint => "bp",
+        }
+    }
+}
+
+/// Settings of percent formatting.
+///
+/// | Field | Description | Default |
+/// | ------------------- | ------------- | ------------- |
+/// | unit                | percent, per mille or basis points | |
+/// | symbol              | written next to the value | the symbol of the unit |
+/// | space               | written between the value and the symbol | empty, ` ` for basis points |
+/// | symbol_first        | the symbol goes before the value, like `%5` | false |
+/// | scale               | values are fractions and are multiplied by the unit, `0.0525` is `5.25%` | true |
+/// | min_fraction        | minimum number of fraction digits | 0 |
+/// | precision           | maximum number of fraction digits | 2 |
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Percent {
+    unit: PercentUnit,
+    symbol: String,
+    space: String,
+    symbol_first: bool,
+    scale: bool,
+    min_fraction: usize,
+    precision: usize,
+}
+
+impl Default for Percent {
+    /// Returns percent settings.
+    fn default() -> Self {
+        Self::new(PercentUnit::Percent)
+    }
+}
+
+impl Percent {
+    /// Create Percent for a unit with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Percent, PercentUnit};
+    /// let ac = Accounting::new_from("$", 2);
+    /// assert_eq!(ac.format_percent(0.0525, &Percent::new(PercentUnit::Percent)), "5.25%");
+    /// assert_eq!(ac.format_percent(0.0525, &Percent::new(PercentUnit::PerMille)), "52.5‰");
+    /// assert_eq!(ac.format_percent(-0.0525, &Percent::new(PercentUnit::BasisPoint)), "-525 bp");
+    /// ```
+    pub fn new(unit: PercentUnit) -> Self {
+        Percent {
+            unit,
+            symbol: unit.symbol().to_string(),
+            space: if unit == PercentUnit::BasisPoint { " ".to_string() } else { String::new() },
+            symbol_first: false,
+            scale: true,
+            min_fraction: 0,
+            precision: 2,
+        }
+    }
+
+    /// Returns the unit.
+    pub fn unit(&self) -> PercentUnit {
+        self.unit
+    }
+
+    /// Sets the symbol written next to the value, like `pct` instead of `%`.
+    pub fn set_symbol(&mut self, symbol: &str) {
+        self.symbol = symbol.to_string();
+    }
+
+    /// Sets the text between the value and the symbol, like a non-breaking space.
+    pub fn set_space(&mut self, space: &str) {
+        self.space = space.to_string();
+    }
+
+    /// Sets whether the symbol goes before the value, like `%5` in Turkish.
+    pub fn set_symbol_first(&mut self, symbol_first: bool) {
+        self.symbol_first = symbol_first;
+    }
+
+    /// Sets whether values are fractions multiplied by the unit, the default, or are already in
+    /// the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Percent};
+    /// let ac = Accounting::new_from("$", 2);
+    /// let mut percent = Percent::default();
+    /// percent.set_scale(false);
+    /// assert_eq!(ac.format_percent(5.25, &percent), "5.25%");
+    /// ```
+    pub fn set_scale(&mut self, scale: bool) {
+        self.scale = scale;
+    }
+
+    /// Sets the minimum number of fraction digits, like
+    /// [Accounting::set_min_fraction_digits]. The maximum is raised if it is lower.
+    pub fn set_min_fraction_digits(&mut self, digits: usize) {
+        self.min_fraction = digits;
+        self.precision = self.precision.max(digits);
+    }
+
+    /// Sets the maximum number of fraction digits the scaled value is rounded to.
+    /// The minimum is lowered if it is higher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Percent};
+    /// let ac = Accounting::new_from("$", 2);
+    /// let mut percent = Percent::default();
+    /// percent.set_max_fraction_digits(1);
+    /// assert_eq!(ac.format_percent(0.12345, &percent), "12.3%");
+    /// percent.set_min_fraction_digits(1);
+    /// assert_eq!(ac.format_percent(0.12, &percent), "12.0%");
+    /// ```
+    pub fn set_max_fraction_digits(&mut self, digits: usize) {
+        self.precision = digits;
+        self.min_fraction = self.min_fraction.min(digits);
+    }
+}
+
+impl Accounting {
+    /// Formats a rate with the settings of `percent`, see [percent](crate::percent).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, Percent};
+    /// let mut ac = Accounting::for_currency("SEK").unwrap();
+    /// ac.set_format_negative("{v}{sign} {s}").unwrap();
+    /// assert_eq!(ac.format_percent(-0.015, &Percent::default()), "1,5%-");
+    /// ```
+    pub fn format_percent<T: FormatNumber>(&self, value: T, percent: &Percent) -> String {
+        let mut result = String::new();
+        // Writing to a `String` never fails.
+        self.write_percent(&mut result, value, percent).unwrap();
+        result
+    }
+
+    /// Writes a rate into `w`, like [Accounting::format_percent].
+    pub fn write_percent<W: fmt::Write, T: FormatNumber>(&self, w: &mut W, value: T, percent: &Percent) -> fmt::Result {
+        let mut digits = value.to_digits();
+        if percent.scale {
+            digits = digits.shift(percent.unit.exponent());
+        }
+        let rounded = digits.round(percent.precision, self.rounding);
+        let value = rounded.abs();
+        let mut format = self.number_format();
+        format.precision = percent.min_fraction;
+
+        let symbol = format!("{}{}", percent.symbol, percent.space);
+        let suffix = format!("{}{}", percent.space, percent.symbol);
+        let template = if percent.symbol_first {
+            self.template(&rounded).without_currency(&symbol, "")
+        } else {
+            self.template(&rounded).without_currency("", &suffix)
+        };
+        let placeholders = Placeholders {
+            symbol: "",
+            code: "",
+            name: "",
+            negative: rounded.is_negative(),
+            value: &value,
+            suffix: "",
+            format: &format,
+        };
+        template.write(w, &placeholders)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Accounting, Grouping, Percent, PercentUnit, RoundingMode};
+
+    #[test]
+    fn percent_test() {
+        let ac = Accounting::new_from("$", 2);
+        let percent = Percent::default();
+        assert_eq!(ac.format_percent(0.0525, &percent), "5.25%");
+        assert_eq!(ac.format_percent(1, &percent), "100%");
+        assert_eq!(ac.format_percent(-0.5f32, &percent), "-50%");
+        assert_eq!(ac.format_percent(123.456789, &percent), "12,345.68%");
+        assert_eq!(ac.format_percent(0, &percent), "0%");
+        assert_eq!(ac.format_percent(f64::NAN, &percent), "NaN%");
+        #[cfg(feature = "decimal")]
+        assert_eq!(ac.format_percent(rust_decimal::Decimal::new(525, 4), &percent), "5.25%");
+
+        let mut percent = Percent::new(PercentUnit::PerMille);
+        percent.set_min_fraction_digits(1);
+        assert_eq!(ac.format_percent(0.0525, &percent), "52.5‰");
+        assert_eq!(ac.format_percent(0.05, &percent), "50.0‰");
+
+        let mut percent = Percent::new(PercentUnit::BasisPoint);
+        assert_eq!(ac.format_percent(0.000125, &percent), "1.25 bp");
+        percent.set_symbol("bps");
+        percent.set_max_fraction_digits(0);
+        assert_eq!(ac.format_percent(0.000125, &percent), "1 bps");
+    }
+
+    #[test]
+    fn accounting_settings_test() {
+        let mut ac = Accounting::new_from_seperator("₹", 2, ".", ",");
+        ac.set_grouping(Grouping::indian());
+        ac.set_format("{v} {s}").unwrap();
+        ac.set_format_negative("({v} {c})").unwrap();
+        ac.set_format_zero("{s} --").unwrap();
+        let mut percent = Percent::default();
+        percent.set_space(" ");
+        assert_eq!(ac.format_percent(12345.6789, &percent), "12.34.567,89 %");
+        assert_eq!(ac.format_percent(-0.00125, &percent), "(0,12 %)");
+        assert_eq!(ac.format_percent(0.0, &percent), "--");
+        // Rounded to zero.
+        assert_eq!(ac.format_percent(0.00001, &percent), "--");
+
+        ac.set_rounding_mode(RoundingMode::Ceiling);
+        assert_eq!(ac.format_percent(0.00001, &percent), "0,01 %");
+        ac.set_format_negative("{s}{sign}{v}").unwrap();
+        percent.set_symbol_first(true);
+        assert_eq!(ac.format_percent(-0.5, &percent), "-% 50");
+    }
+}
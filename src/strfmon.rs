@@ -0,0 +1,333 @@
+//! POSIX `strfmon` format strings, like `%=*#10.2n` or `%!i`, for porting C reports.
+//!
+//! A conversion is `%[flags][width][#left][.right](n|i)`, `%%` is a literal `%`:
+//! - `=f` fills the left precision with `f` instead of spaces.
+//! - `^` turns off grouping.
+//! - `+` writes negatives with the negative format string, the default.
+//! - `(` encloses negatives in parentheses instead.
+//! - `!` leaves out the currency symbol.
+//! - `-` justifies to the left within the width.
+//! - `width` is the minimum width of the conversion, padded with spaces.
+//! - `#left` pads the integer part with the fill character to `left` digits and their group
+//!   separators, the prefixes of positive and negative values are padded to the same width.
+//! - `.right` is the number of fraction digits, the precision of the Accounting by default.
+//! - `n` writes the symbol, `i` the ISO 4217 code, separated from the value by a space.
+//!
+//! Positions and sign come from the format strings of the Accounting, the separators, grouping
+//! and rounding mode from its settings. Widths count bytes, as in C.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::Accounting;
+//! let ac = Accounting::for_currency("USD").unwrap();
+//! assert_eq!(ac.strfmon("%=*#10.2n", &[1234.567]).unwrap(), " $********1,234.57");
+//! assert_eq!(ac.strfmon("[%(i] [%!n]", &[-5, 5]).unwrap(), "[(USD 5.00)] [5.00]");
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::format_number::{Digits, FormatNumber, Grouping};
+use crate::template::{Template, Token};
+use crate::Accounting;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrfmonError {
+    /// The conversion starting at this byte offset is malformed.
+    InvalidConversion(usize),
+    /// The conversion starting at this byte offset has no value left.
+    MissingValue(usize),
+}
+
+impl fmt::Display for StrfmonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StrfmonError::InvalidConversion(i) => write!(f, "invalid conversion at {}", i),
+            StrfmonError::MissingValue(i) => write!(f, "missing value for conversion at {}", i),
+        }
+    }
+}
+
+impl error::Error for StrfmonError {}
+
+/// A parsed `%...n` or `%...i` conversion.
+struct Conversion {
+    fill: char,
+    grouping: bool,
+    parentheses: bool,
+    symbol: bool,
+    left_justify: bool,
+    width: usize,
+    left_precision: Option<usize>,
+    right_precision: Option<usize>,
+    international: bool,
+}
+
+impl Conversion {
+    /// Parses a conversion after its `%`, returning it and the remaining string.
+    fn parse(spec: &str) -> Option<(Conversion, &str)> {
+        let mut conversion = Conversion {
+            fill: ' ',
+            grouping: true,
+            parentheses: false,
+            symbol: true,
+            left_justify: false,
+            width: 0,
+            left_precision: None,
+            right_precision: None,
+            international: false,
+        };
+        let (mut sign, mut rest) = (false, spec);
+        loop {
+            let mut chars = rest.chars();
+            match chars.next()? {
+                '=' => conversion.fill = chars.next()?,
+                '^' => conversion.grouping = false,
+                '+' if !conversion.parentheses => sign = true,
+                '(' if !sign => conversion.parentheses = true,
+                '!' => conversion.symbol = false,
+                '-' => conversion.left_justify = true,
+                _ => break,
+            }
+            rest = chars.as_str();
+        }
+
+        if let Some((width, r)) = split_number(rest) {
+            conversion.width = width;
+            rest = r;
+        }
+        if let Some(r) = rest.strip_prefix('#') {
+            let (left, r) = split_number(r)?;
+            conversion.left_precision = Some(left);
+            rest = r;
+        }
+        if let Some(r) = rest.strip_prefix('.') {
+            let (right, r) = split_number(r)?;
+            conversion.right_precision = Some(right);
+            rest = r;
+        }
+        let mut chars = rest.chars();
+        conversion.international = match chars.next()? {
+            'n' => false,
+            'i' => true,
+            _ => return None,
+        };
+        Some((conversion, chars.as_str()))
+    }
+}
+
+/// Splits the leading decimal number off `s`.
+fn split_number(s: &str) -> Option<(usize, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+fn is_currency(token: &Token) -> bool {
+    matches!(token, Token::Symbol | Token::Code | Token::Name)
+}
+
+impl Accounting {
+    /// Formats `values` with a POSIX `strfmon` format string, see [strfmon](crate::strfmon) for the
+    /// conversions. Every conversion takes the next value, values left over are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, StrfmonError};
+    /// let mut ac = Accounting::new_from_seperator("€", 2, ".", ",");
+    /// ac.set_code("EUR");
+    /// ac.set_format("{v} {s}").unwrap();
+    /// assert_eq!(ac.strfmon("%#6n|%(#6i", &[-1234.567, 1234.567]).unwrap(), "-  1.234,57 €|   1.234,57 EUR");
+    /// assert_eq!(ac.strfmon("%!n = 100%%", &[1]).unwrap(), "1,00 = 100%");
+    ///
+    /// assert_eq!(ac.strfmon("%n %n", &[1]), Err(StrfmonError::MissingValue(3)));
+    /// assert_eq!(ac.strfmon("%15(n", &[1]), Err(StrfmonError::InvalidConversion(0)));
+    /// ```
+    pub fn strfmon<T: FormatNumber>(&self, format: &str, values: &[T]) -> Result<String, StrfmonError> {
+        let mut result = String::new();
+        let mut values = values.iter();
+        let mut rest = format;
+        while let Some(i) = rest.find('%') {
+            result.push_str(&rest[..i]);
+            let offset = format.len() - rest.len() + i;
+            if let Some(r) = rest[i + 1..].strip_prefix('%') {
+                result.push('%');
+                rest = r;
+                continue;
+            }
+            let (conversion, r) =
+                Conversion::parse(&rest[i + 1..]).ok_or(StrfmonError::InvalidConversion(offset))?;
+            let value = values.next().ok_or(StrfmonError::MissingValue(offset))?;
+            self.write_conversion(&mut result, &conversion, value.to_digits());
+            rest = r;
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+
+    fn write_conversion(&self, result: &mut String, conversion: &Conversion, digits: Digits) {
+        let mut format = self.number_format();
+        format.precision = conversion.right_precision.unwrap_or(self.precision);
+        if !conversion.grouping {
+            format.grouping = Grouping::none();
+        }
+        // The sign comes from the value before rounding, like `-$0` for `-0.4` in C.
+        let negative = digits.is_negative();
+        let abs = digits.round(format.precision, format.rounding).abs();
+
+        let mut number = String::new();
+        // Writing to a `String` never fails.
+        abs.write_integer(&mut number, &format).unwrap();
+        if let Some(left) = conversion.left_precision {
+            let mut padded = String::new();
+            format.grouping.write(&mut padded, std::iter::repeat_n(b'0', left), left, format.thousand).unwrap();
+            let fill = padded.chars().count().saturating_sub(number.chars().count());
+            number.insert_str(0, &conversion.fill.to_string().repeat(fill));
+        }
+        abs.write_fraction(&mut number, &format).unwrap();
+
+        let template = if negative && !conversion.parentheses {
+            &self.format_negative
+        } else if abs.is_zero() && !negative {
+            &self.format_zero
+        } else {
+            &self.format_positive
+        };
+        let (mut before, after, has_value) = strfmon_affixes(self, conversion, template, negative);
+        if conversion.left_precision.is_some() {
+            // Pad the prefix so positive and negative values line up.
+            let negative_template = if conversion.parentheses { &self.format_positive } else { &self.format_negative };
+            let width = [(&self.format_positive, false), (&self.format_zero, false), (negative_template, true)]
+                .iter()
+                .map(|&(template, negative)| strfmon_affixes(self, conversion, template, negative).0.len())
+                .max();
+            let padding = width.unwrap_or(0).saturating_sub(before.len());
+            before.insert_str(0, &" ".repeat(padding));
+        }
+
+        let mut field = before;
+        if has_value {
+            field.push_str(&number);
+        }
+        field.push_str(&after);
+        let padding = " ".repeat(conversion.width.saturating_sub(field.len()));
+        if conversion.left_justify {
+            result.push_str(&field);
+            result.push_str(&padding);
+        } else {
+            result.push_str(&padding);
+            result.push_str(&field);
+        }
+    }
+
+}
+
+/// Returns the text of `template` before and after the value, and whether it has a value.
+fn strfmon_affixes(
+    ac: &Accounting,
+    conversion: &Conversion,
+    template: &Template,
+    negative: bool,
+) -> (String, String, bool) {
+    let parentheses = negative && conversion.parentheses;
+    let tokens = template.tokens();
+    let value = tokens.iter().position(|t| *t == Token::Value);
+
+    let (mut before, mut after) = (String::new(), String::new());
+    for (i, token) in tokens.iter().enumerate() {
+        let text = match value {
+            Some(v) if i > v => &mut after,
+            _ => &mut before,
+        };
+        let next_to = |j: Option<usize>, f: fn(&Token) -> bool| j.and_then(|j| tokens.get(j)).is_some_and(f);
+        match token {
+            // Without a symbol, whitespace that separated it from the value goes too.
+            Token::Literal(s) if !conversion.symbol && s.chars().all(char::is_whitespace)
+                && (next_to(i.checked_sub(1), is_currency) || next_to(Some(i + 1), is_currency)) => {}
+            Token::Literal(s) => text.push_str(s),
+            _ if is_currency(token) && !conversion.symbol => {}
+            Token::Symbol if conversion.international && !ac.code.is_empty() => {
+                let value_next = |t: &Token| matches!(t, Token::Value | Token::Sign);
+                if next_to(i.checked_sub(1), value_next) {
+                    text.push(' ');
+                }
+                text.push_str(&ac.code);
+                if next_to(Some(i + 1), value_next) {
+                    text.push(' ');
+                }
+            }
+            Token::Symbol => text.push_str(&ac.symbol),
+            Token::Code => text.push_str(&ac.code),
+            Token::Name => text.push_str(&ac.name),
+            Token::Sign if negative && !parentheses => text.push('-'),
+            Token::Sign | Token::Value => {}
+        }
+    }
+    if parentheses {
+        before.insert(0, '(');
+        after.push(')');
+    }
+    (before, after, value.is_some())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::Accounting;
+
+    // Expected strings are the output of glibc `strfmon` in the `en_US.UTF-8` and `de_DE.UTF-8` locales.
+    #[test]
+    fn strfmon_test() {
+        let ac = Accounting::for_currency("USD").unwrap();
+        let strfmon = |format: &str, value: f64| ac.strfmon(format, &[value]).unwrap();
+        assert_eq!(strfmon("%n", -1234.567), "-$1,234.57");
+        assert_eq!(strfmon("%i", -1234.567), "-USD 1,234.57");
+        assert_eq!(strfmon("%(n", -1234.567), "($1,234.57)");
+        assert_eq!(strfmon("%+n", 1234.567), "$1,234.57");
+        assert_eq!(strfmon("%^n", 1234.567), "$1234.57");
+        assert_eq!(strfmon("%=*#10.2n", -1234.567), "-$********1,234.57");
+        assert_eq!(strfmon("%=*#10.2n", 0.0), " $************0.00");
+        assert_eq!(strfmon("%=*^#6n", 0.0), " $*****0.00");
+        assert_eq!(strfmon("%=0#8n", 1234.567), " $000001,234.57");
+        assert_eq!(strfmon("%#6n", 5.0), " $      5.00");
+        assert_eq!(strfmon("%#3n", 1234.567), " $1,234.57");
+        assert_eq!(strfmon("%(#6n", 1234.567), " $  1,234.57");
+        assert_eq!(strfmon("%(#6n", -0.5), "($      0.50)");
+        assert_eq!(strfmon("%10n", -0.5), "    -$0.50");
+        assert_eq!(strfmon("%-10n|", 5.0), "$5.00     |");
+        assert_eq!(strfmon("%.0n", -0.5), "-$0");
+        assert_eq!(strfmon("%.3i", 1234.567), "USD 1,234.567");
+        assert_eq!(strfmon("%(!i", -1234.567), "(1,234.57)");
+        assert_eq!(strfmon("%#5.0i", 0.0), " USD      0");
+        assert_eq!(strfmon("%(10.1n|", -0.5), "    ($0.5)|");
+        assert_eq!(strfmon("%-(#6.1i|", 5.0), " USD       5.0|");
+    }
+
+    #[test]
+    fn strfmon_suffix_test() {
+        let mut ac = Accounting::new_from_seperator("€", 2, ".", ",");
+        ac.set_code("EUR");
+        ac.set_format("{v} {s}").unwrap();
+        let strfmon = |format: &str, value: f64| ac.strfmon(format, &[value]).unwrap();
+        assert_eq!(strfmon("%i", -1234.567), "-1.234,57 EUR");
+        assert_eq!(strfmon("%(n", -1234.567), "(1.234,57 €)");
+        assert_eq!(strfmon("%#6n", -1234.567), "-  1.234,57 €");
+        assert_eq!(strfmon("%12n|", 1234.567), "1.234,57 €|");
+        assert_eq!(strfmon("%-12i|", 1234.567), "1.234,57 EUR|");
+        assert_eq!(strfmon("%!n", -1234.567), "-1.234,57");
+        assert_eq!(strfmon("%=*#4n", 1234.567), " 1.234,57 €");
+    }
+
+    #[test]
+    fn strfmon_error_test() {
+        use crate::StrfmonError::*;
+        let ac = Accounting::new_from("$", 2);
+        assert_eq!(ac.strfmon("%", &[1]), Err(InvalidConversion(0)));
+        assert_eq!(ac.strfmon("a %+(n", &[1]), Err(InvalidConversion(2)));
+        assert_eq!(ac.strfmon("%#n", &[1]), Err(InvalidConversion(0)));
+        assert_eq!(ac.strfmon("%10.x", &[1]), Err(InvalidConversion(0)));
+        assert_eq!(ac.strfmon("%d", &[1]), Err(InvalidConversion(0)));
+        assert_eq!(ac.strfmon::<i32>("%% %n", &[]), Err(MissingValue(3)));
+        assert_eq!(ac.strfmon::<i32>("%%", &[]), Ok("%".to_string()));
+    }
+}
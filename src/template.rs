@@ -0,0 +1,390 @@
+//! Format templates such as `{s} {v}`, parsed once into tokens.
+//!
+//! In a template:
+//! - `{v}` is replaced by the value, without its sign.
+//! - `{s}` is replaced by the currency symbol.
+//! - `{c}` is replaced by the ISO 4217 currency code.
+//! - `{n}` is replaced by the currency name.
+//! - `{sign}` is replaced by `-` for negative values and by nothing otherwise.
+//! - `{nbsp}` is a non-breaking space.
+//! - `{{` and `}}` are a literal `{` and `}`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{Accounting, TemplateError};
+//! let mut ac = Accounting::for_currency("USD").unwrap();
+//! ac.set_format("{c} {v}").unwrap();
+//! assert_eq!(ac.format_money(1000), "USD 1,000.00");
+//! ac.set_format("{v} {n}").unwrap();
+//! assert_eq!(ac.format_money(1000), "1,000.00 US Dollar");
+//! ac.set_format("{s}{nbsp}{sign}{v}").unwrap();
+//! assert_eq!(ac.format_money(-1000), "$\u{a0}-1,000.00");
+//! ac.set_format_positive("{s}{v} {{v}}").unwrap();
+//! assert_eq!(ac.format_money(5), "$5.00 {v}");
+//!
+//! assert_eq!(ac.set_format_positive("{s} {x}"), Err(TemplateError::UnknownPlaceholder("x".to_string())));
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::format_number::{Digits, NumberFormat};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A `{` at this byte offset has no closing `}`.
+    UnclosedBrace(usize),
+    /// A `}` at this byte offset has no opening `{`, write `}}` for a literal brace.
+    UnmatchedBrace(usize),
+    /// The placeholder between the braces is not known.
+    UnknownPlaceholder(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TemplateError::UnclosedBrace(i) => write!(f, "unclosed '{{' at {}", i),
+            TemplateError::UnmatchedBrace(i) => write!(f, "unmatched '}}' at {}", i),
+            TemplateError::UnknownPlaceholder(ref name) => write!(f, "unknown placeholder {{{}}}", name),
+        }
+    }
+}
+
+impl error::Error for TemplateError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token {
+    Literal(String),
+    Symbol,
+    Code,
+    Name,
+    Sign,
+    Value,
+}
+
+/// The values substituted for the placeholders of a template.
+pub(crate) struct Placeholders<'a> {
+    pub symbol: &'a str,
+    pub code: &'a str,
+    pub name: &'a str,
+    pub negative: bool,
+    /// The absolute value, already rounded.
+    pub value: &'a Digits,
+    /// Written right after the value, like `K` in compact notation.
+    pub suffix: &'a str,
+    pub format: &'a NumberFormat<'a>,
+}
+
+/// A parsed format template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Template {
+    source: String,
+    tokens: Vec<Token>,
+}
+
+impl Template {
+    pub(crate) fn parse(source: &str) -> Result<Self, TemplateError> {
+        let tokens = parse_tokens(source, false)?;
+        Ok(Template { source: source.to_string(), tokens })
+    }
+
+    /// Parses like [Template::parse], but keeps malformed braces and unknown placeholders as
+    /// literal text. The source is rewritten with escaped braces, so it parses again.
+    pub(crate) fn parse_lenient(source: &str) -> Self {
+        // Nothing is an error in lenient mode.
+        let tokens = parse_tokens(source, true).unwrap();
+        Template { source: write_source(&tokens), tokens }
+    }
+
+    /// Returns the template as it was written.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub(crate) fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// Returns true if the template places the sign itself with `{sign}`.
+    pub(crate) fn has_sign(&self) -> bool {
+        self.tokens.contains(&Token::Sign)
+    }
+
+    pub(crate) fn write<W: fmt::Write>(&self, w: &mut W, placeholders: &Placeholders) -> fmt::Result {
+        write_tokens(&self.tokens, w, placeholders)
+    }
+
+    /// Writes the parts before and after the first `{v}`, without the value itself.
+    /// The part before is split after the last `{s}`, `{c}` or `{n}`: `lead` gets the text up to
+    /// the placeholder and `before` the text next to the value, like a sign.
+    /// Returns false and writes everything into `lead` if there is no `{v}`.
+    pub(crate) fn write_around_value<W: fmt::Write>(
+        &self,
+        lead: &mut W,
+        before: &mut W,
+        after: &mut W,
+        placeholders: &Placeholders,
+    ) -> Result<bool, fmt::Error> {
+        match self.tokens.iter().position(|t| *t == Token::Value) {
+            Some(i) => {
+                let currency = |t: &Token| matches!(t, Token::Symbol | Token::Code | Token::Name);
+                let j = self.tokens[..i].iter().rposition(currency).map_or(0, |j| j + 1);
+                write_tokens(&self.tokens[..j], lead, placeholders)?;
+                write_tokens(&self.tokens[j..i], before, placeholders)?;
+                write_tokens(&self.tokens[i + 1..], after, placeholders)?;
+                Ok(true)
+            }
+            None => {
+                write_tokens(&self.tokens, lead, placeholders)?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Returns the template without `{s}`, `{c}` and `{n}` and the spaces next to them, with
+    /// `before` and `after` written around the value, like `({v} %)` from `({s}{v})`.
+    pub(crate) fn without_currency(&self, before: &str, after: &str) -> Template {
+        let currency = |t: &Token| matches!(t, Token::Symbol | Token::Code | Token::Name);
+        let mut tokens = Vec::new();
+        for (i, token) in self.tokens.iter().enumerate() {
+            match token {
+                Token::Literal(s) => {
+                    let mut s = s.as_str();
+                    if i > 0 && currency(&self.tokens[i - 1]) {
+                        s = s.trim_start();
+                    }
+                    if self.tokens.get(i + 1).is_some_and(currency) {
+                        s = s.trim_end();
+                    }
+                    push_literal(&mut tokens, s);
+                }
+                Token::Value => {
+                    push_literal(&mut tokens, before);
+                    tokens.push(Token::Value);
+                    push_literal(&mut tokens, after);
+                }
+                token if currency(token) => {}
+                token => tokens.push(token.clone()),
+            }
+        }
+        Template { source: write_source(&tokens), tokens }
+    }
+}
+
+/// Parses a template into tokens. In lenient mode malformed braces and unknown placeholders
+/// are literal text instead of errors.
+fn parse_tokens(source: &str, lenient: bool) -> Result<Vec<Token>, TemplateError> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' if lenient => literal.push('}'),
+            '}' => return Err(TemplateError::UnmatchedBrace(i)),
+            '{' => {
+                let rest = &source[i + 1..];
+                let end = match rest.find('}') {
+                    Some(end) => end,
+                    None if lenient => {
+                        literal.push('{');
+                        continue;
+                    }
+                    None => return Err(TemplateError::UnclosedBrace(i)),
+                };
+                let token = match &rest[..end] {
+                    "s" => Token::Symbol,
+                    "c" => Token::Code,
+                    "n" => Token::Name,
+                    "sign" => Token::Sign,
+                    "v" => Token::Value,
+                    "nbsp" => Token::Literal('\u{a0}'.to_string()),
+                    // The `{` is text, and what follows is read again.
+                    _ if lenient => {
+                        literal.push('{');
+                        continue;
+                    }
+                    name => return Err(TemplateError::UnknownPlaceholder(name.to_string())),
+                };
+                match token {
+                    Token::Literal(s) => literal.push_str(&s),
+                    token => {
+                        if !literal.is_empty() {
+                            tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                        }
+                        tokens.push(token);
+                    }
+                }
+                while chars.next_if(|&(j, _)| j <= i + 1 + end).is_some() {}
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    Ok(tokens)
+}
+
+/// Writes tokens back as a template source, with the braces of literals escaped.
+fn write_source(tokens: &[Token]) -> String {
+    let mut source = String::new();
+    for token in tokens.iter() {
+        match token {
+            Token::Literal(s) => source.push_str(&s.replace('{', "{{").replace('}', "}}")),
+            Token::Symbol => source.push_str("{s}"),
+            Token::Code => source.push_str("{c}"),
+            Token::Name => source.push_str("{n}"),
+            Token::Sign => source.push_str("{sign}"),
+            Token::Value => source.push_str("{v}"),
+        }
+    }
+    source
+}
+
+/// Appends `s` to the tokens, merged into a preceding literal.
+fn push_literal(tokens: &mut Vec<Token>, s: &str) {
+    if s.is_empty() {
+        return;
+    }
+    match tokens.last_mut() {
+        Some(Token::Literal(literal)) => literal.push_str(s),
+        _ => tokens.push(Token::Literal(s.to_string())),
+    }
+}
+
+fn write_tokens<W: fmt::Write>(tokens: &[Token], w: &mut W, placeholders: &Placeholders) -> fmt::Result {
+    for token in tokens.iter() {
+        match token {
+            Token::Literal(s) => w.write_str(s)?,
+            Token::Symbol => w.write_str(placeholders.symbol)?,
+            Token::Code => w.write_str(placeholders.code)?,
+            Token::Name => w.write_str(placeholders.name)?,
+            Token::Sign if placeholders.negative => w.write_char('-')?,
+            Token::Sign => {}
+            Token::Value => {
+                placeholders.value.write(w, placeholders.format)?;
+                w.write_str(placeholders.suffix)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &Template, placeholders: &Placeholders) -> String {
+        let mut result = String::new();
+        template.write(&mut result, placeholders).unwrap();
+        result
+    }
+
+    #[test]
+    fn parse_test() {
+        let t = Template::parse("{s} ({v})").unwrap();
+        assert_eq!(t.tokens, [
+            Token::Symbol,
+            Token::Literal(" (".to_string()),
+            Token::Value,
+            Token::Literal(")".to_string()),
+        ]);
+        assert_eq!(t.as_str(), "{s} ({v})");
+        assert_eq!(Template::parse("").unwrap().tokens, []);
+        assert_eq!(Template::parse("{{{v}}}").unwrap().tokens, [
+            Token::Literal("{".to_string()),
+            Token::Value,
+            Token::Literal("}".to_string()),
+        ]);
+        assert_eq!(Template::parse("{v}{nbsp}{c}").unwrap().tokens, [
+            Token::Value,
+            Token::Literal("\u{a0}".to_string()),
+            Token::Code,
+        ]);
+        assert!(Template::parse("{s}{sign}{v}").unwrap().has_sign());
+        assert!(!Template::parse("{s}{{sign}}{v}").unwrap().has_sign());
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert_eq!(Template::parse("{s"), Err(TemplateError::UnclosedBrace(0)));
+        assert_eq!(Template::parse("{v} }"), Err(TemplateError::UnmatchedBrace(4)));
+        assert_eq!(Template::parse("{}"), Err(TemplateError::UnknownPlaceholder("".to_string())));
+        assert_eq!(Template::parse("{V}"), Err(TemplateError::UnknownPlaceholder("V".to_string())));
+        assert_eq!(Template::parse("{ s}"), Err(TemplateError::UnknownPlaceholder(" s".to_string())));
+    }
+
+    #[test]
+    fn parse_lenient_test() {
+        for (source, expected) in [("-{s}{v", "-{s}{{v"), ("{v}}", "{v}}}"), ("{x{v}", "{{x{v}"), ("{s}{nbsp}{v}", "{s}\u{a0}{v}")] {
+            let template = Template::parse_lenient(source);
+            assert_eq!(template.as_str(), expected);
+            assert_eq!(Template::parse(expected).unwrap(), template);
+        }
+    }
+
+    #[test]
+    fn without_currency_test() {
+        let percent = |source: &str| {
+            let t = Template::parse(source).unwrap().without_currency("", "\u{a0}%");
+            assert_eq!(Template::parse(t.as_str()).unwrap(), t);
+            t.as_str().to_string()
+        };
+        assert_eq!(percent("{s}{v}"), "{v}\u{a0}%");
+        assert_eq!(percent("-{s}{v}"), "-{v}\u{a0}%");
+        assert_eq!(percent("{v} {s}"), "{v}\u{a0}%");
+        assert_eq!(percent("({v} {c})"), "({v}\u{a0}%)");
+        assert_eq!(percent("{s} ({v})"), "({v}\u{a0}%)");
+        assert_eq!(percent("{s}\u{a0}{sign}{v}"), "{sign}{v}\u{a0}%");
+        assert_eq!(percent("{n} {{{v}}}"), "{{{v}\u{a0}%}}");
+        assert_eq!(percent("{s} --"), "--");
+        let t = Template::parse("{s}-{v}").unwrap().without_currency("%", "");
+        assert_eq!(t.tokens, [Token::Literal("-%".to_string()), Token::Value]);
+    }
+
+    #[test]
+    fn write_test() {
+        let value = "1".parse().unwrap();
+        let format = NumberFormat::new(2, ",", ".");
+        let mut placeholders = Placeholders {
+            symbol: "{v}",
+            code: "USD",
+            name: "US Dollar",
+            negative: false,
+            value: &value,
+            suffix: "",
+            format: &format,
+        };
+        let t = Template::parse("{s}{v} {{s}}").unwrap();
+        assert_eq!(render(&t, &placeholders), "{v}1.00 {s}");
+        let t = Template::parse("€ --").unwrap();
+        assert_eq!(render(&t, &placeholders), "€ --");
+        let t = Template::parse("{v}{sign} {c}, {n}").unwrap();
+        assert_eq!(render(&t, &placeholders), "1.00 USD, US Dollar");
+        placeholders.negative = true;
+        assert_eq!(render(&t, &placeholders), "1.00- USD, US Dollar");
+
+        let mut parts = (String::new(), String::new(), String::new());
+        let t = Template::parse("({s}{sign}{v}{sign})").unwrap();
+        assert!(t.write_around_value(&mut parts.0, &mut parts.1, &mut parts.2, &placeholders).unwrap());
+        assert_eq!(parts, ("({v}".to_string(), "-".to_string(), "-)".to_string()));
+        let mut parts = (String::new(), String::new(), String::new());
+        let t = Template::parse("{sign}{v} {s}").unwrap();
+        assert!(t.write_around_value(&mut parts.0, &mut parts.1, &mut parts.2, &placeholders).unwrap());
+        assert_eq!(parts, ("".to_string(), "-".to_string(), " {v}".to_string()));
+        let mut parts = (String::new(), String::new(), String::new());
+        let t = Template::parse("{s} --").unwrap();
+        assert!(!t.write_around_value(&mut parts.0, &mut parts.1, &mut parts.2, &placeholders).unwrap());
+        assert_eq!(parts, ("{v} --".to_string(), "".to_string(), "".to_string()));
+    }
+}
@@ -1,6 +1,6 @@
 //! Strip out all currency formatting and return the numberic string.  
 //! 
-//! The [unformat] function pulls the currency descripter from the locale_info_map and 
+//! The [unformat] function pulls the currency descripter from the [Currency] registry and 
 //! uses it to return an unformatted value based on thousands seperator and decimal seperator.
 //! 
 //! # Examples
@@ -11,13 +11,11 @@
 //! assert_eq!(unformat("$45,567.10", 2, "zzz"), Err(UnformatError::NoLocaleFound));
 //! ```
 
-mod locale;
-
 use std::fmt;
 use std::error;
 use std::num::ParseFloatError;
 use regex::Regex;
-use locale::{Locale, locale_info_map};
+use crate::currency::Currency;
 
 type Result<T> = std::result::Result<T, UnformatError>;
 
@@ -54,22 +52,18 @@ impl From<ParseFloatError> for UnformatError {
 /// Takes a string of the number to strip currency info on
 /// and precision for decimals.
 pub fn unformat(n: &str, precision: usize, currency: &str) -> Result<String> {
-	let lc: Locale;
-	let currency = currency.to_uppercase();
-	
-	if let Some(val)=  locale_info_map(&currency) {
-		lc = val;
-	} else {
-		return Err(UnformatError::NoLocaleFound);
-	}
+	let lc = match Currency::from_code(currency) {
+		Some(val) => val,
+		None => return Err(UnformatError::NoLocaleFound),
+	};
 
 	let r = Regex::new(r"[^0-9-., ]").unwrap();
 	let mut num = r.replace_all(n, "").into_owned();
-	num = num.replace(lc.thousands_seperator, "");
+	num = num.replace(lc.thousands_separator(), "");
 
 	// Replace decimal seperator with a decimal
-	if lc.decimal_seperator != "." {
-		num = num.replace(lc.decimal_seperator, ".");
+	if lc.decimal_separator() != "." {
+		num = num.replace(lc.decimal_separator(), ".");
 	}
 
 	let v: f64 = num.trim().parse()?;
@@ -0,0 +1,324 @@
+//! Amounts spelled out in English words, as written on checks.
+//!
+//! Numbers use the American short scale without "and", e.g. `one thousand two hundred thirty-four`.
+//! The minor units are written as a fraction of the major unit, `56/100`, or in words,
+//! `fifty-six cents`, see [MinorStyle].
+//!
+//! # Examples
+//!
+//! ```
+//! # use accounting::{AmountWords, MinorStyle};
+//! let mut words = AmountWords::for_currency("USD").unwrap();
+//! assert_eq!(words.format(1234.56).unwrap(), "One thousand two hundred thirty-four and 56/100 dollars");
+//!
+//! words.set_style(MinorStyle::Words);
+//! assert_eq!(words.format(1234.56).unwrap(), "One thousand two hundred thirty-four dollars and fifty-six cents");
+//! assert_eq!(words.format(1.01).unwrap(), "One dollar and one cent");
+//! ```
+
+use std::error;
+use std::fmt;
+use crate::currency::{Currency, CurrencyError};
+use crate::format_number::{FormatNumber, RoundingMode};
+use crate::Accounting;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordsError {
+    /// The integer part does not fit an `u128`.
+    TooLarge,
+    /// A NaN or infinite number was used.
+    NonFinite,
+}
+
+impl fmt::Display for WordsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WordsError::TooLarge => write!(f, "amount is too large to spell out"),
+            WordsError::NonFinite => write!(f, "amount is not a finite number"),
+        }
+    }
+}
+
+impl error::Error for WordsError {}
+
+/// How the minor units are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MinorStyle {
+    /// `One and 50/100 dollars`, the usual style on checks. The major unit is always plural.
+    #[default]
+    Fraction,
+    /// `One dollar and fifty cents`, zero minor units are left out.
+    Words,
+}
+
+// Major and minor unit names, singular and plural, of common currencies.
+static UNITS: [(&str, [&str; 4]); 16] = [
+    ("AUD", ["dollar", "dollars", "cent", "cents"]),
+    ("CAD", ["dollar", "dollars", "cent", "cents"]),
+    ("CHF", ["franc", "francs", "centime", "centimes"]),
+    ("CNY", ["yuan", "yuan", "fen", "fen"]),
+    ("EUR", ["euro", "euros", "cent", "cents"]),
+    ("GBP", ["pound", "pounds", "penny", "pence"]),
+    ("HKD", ["dollar", "dollars", "cent", "cents"]),
+    ("INR", ["rupee", "rupees", "paisa", "paise"]),
+    ("JPY", ["yen", "yen", "sen", "sen"]),
+    ("KRW", ["won", "won", "jeon", "jeon"]),
+    ("MXN", ["peso", "pesos", "centavo", "centavos"]),
+    ("NZD", ["dollar", "dollars", "cent", "cents"]),
+    ("RUB", ["ruble", "rubles", "kopek", "kopeks"]),
+    ("SEK", ["krona", "kronor", "öre", "öre"]),
+    ("SGD", ["dollar", "dollars", "cent", "cents"]),
+    ("USD", ["dollar", "dollars", "cent", "cents"]),
+];
+
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+// Short scale names of 1000^i, `u128::MAX` is below 1000^13.
+const SCALES: [&str; 13] = [
+    "", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion", "sextillion",
+    "septillion", "octillion", "nonillion", "decillion", "undecillion",
+];
+
+/// Spells out amounts in English words.
+///
+/// | Field | Description | USD |
+/// | --------------- | ------------- | ------------- |
+/// | major           | singular and plural name of the major unit | dollar, dollars |
+/// | minor           | singular and plural name of the minor unit | cent, cents |
+/// | precision       | number of minor unit digits | 2 |
+/// | rounding        | rounding of digits beyond the precision | [RoundingMode::HalfEven] |
+/// | style           | how the minor units are written | [MinorStyle::Fraction] |
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmountWords {
+    major: [String; 2],
+    minor: [String; 2],
+    precision: usize,
+    rounding: RoundingMode,
+    style: MinorStyle,
+}
+
+impl AmountWords {
+    /// Create AmountWords from the singular and plural unit names and the precision.
+    pub fn new(major_singular: &str, major_plural: &str, minor_singular: &str, minor_plural: &str, precision: usize) -> Self {
+        AmountWords {
+            major: [major_singular.to_string(), major_plural.to_string()],
+            minor: [minor_singular.to_string(), minor_plural.to_string()],
+            precision,
+            rounding: RoundingMode::default(),
+            style: MinorStyle::default(),
+        }
+    }
+
+    /// Create AmountWords for a currency of the [Currency] registry.
+    ///
+    /// Currencies without known unit names use the currency name for the major unit,
+    /// and `cent`/`cents` for the minor unit.
+    pub fn for_currency(code: &str) -> Result<Self, CurrencyError> {
+        let currency = Currency::try_from_code(code)?;
+        let [major_singular, major_plural, minor_singular, minor_plural] = UNITS
+            .iter()
+            .find(|(c, _)| *c == currency.code())
+            .map_or([currency.name(), currency.name(), "cent", "cents"], |(_, units)| *units);
+        Ok(Self::new(major_singular, major_plural, minor_singular, minor_plural, currency.exponent() as usize))
+    }
+
+    /// Create AmountWords for the currency code of an [Accounting], with its precision and rounding mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{Accounting, AmountWords};
+    /// let ac = Accounting::for_locale("en-GB", "GBP").unwrap();
+    /// let words = AmountWords::for_accounting(&ac).unwrap();
+    /// assert_eq!(words.format(20.5).unwrap(), "Twenty and 50/100 pounds");
+    /// ```
+    pub fn for_accounting(accounting: &Accounting) -> Result<Self, CurrencyError> {
+        let mut words = Self::for_currency(&accounting.code)?;
+        words.precision = accounting.precision;
+        words.rounding = accounting.rounding;
+        Ok(words)
+    }
+
+    /// Sets how the minor units are written.
+    pub fn set_style(&mut self, style: MinorStyle) {
+        self.style = style;
+    }
+
+    /// Sets the rounding mode used when a value has more fraction digits than the precision.
+    pub fn set_rounding_mode(&mut self, rounding: RoundingMode) {
+        self.rounding = rounding;
+    }
+
+    /// Spells out the value, starting with a capital letter. Negative values start with `Minus`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use accounting::{AmountWords, WordsError};
+    /// let words = AmountWords::for_currency("EUR").unwrap();
+    /// assert_eq!(words.format(-1).unwrap(), "Minus one and 00/100 euros");
+    /// assert_eq!(words.format(i128::MAX).unwrap().split(' ').next(), Some("One"));
+    /// assert_eq!(words.format(f64::NAN), Err(WordsError::NonFinite));
+    /// ```
+    pub fn format<T: FormatNumber>(&self, value: T) -> Result<String, WordsError> {
+        let digits = value.to_digits().round(self.precision, self.rounding);
+        if !digits.is_finite() {
+            return Err(WordsError::NonFinite);
+        }
+        let (major, minor) = digits.to_major_minor(self.precision).ok_or(WordsError::TooLarge)?;
+
+        let mut result = String::new();
+        if digits.is_negative() {
+            result.push_str("minus ");
+        }
+        push_number(&mut result, major);
+        match self.style {
+            MinorStyle::Fraction if self.precision > 0 => {
+                // The unit follows the fraction, so it is always plural.
+                let denominator = format!("1{}", "0".repeat(self.precision));
+                result.push_str(&format!(" and {:0width$}/{} {}", minor, denominator, self.major[1], width = self.precision));
+            }
+            MinorStyle::Fraction => {
+                result.push(' ');
+                result.push_str(&self.major[(major != 1) as usize]);
+            }
+            MinorStyle::Words => {
+                result.push(' ');
+                result.push_str(&self.major[(major != 1) as usize]);
+                if minor != 0 {
+                    result.push_str(" and ");
+                    push_number(&mut result, minor);
+                    result.push(' ');
+                    result.push_str(&self.minor[(minor != 1) as usize]);
+                }
+            }
+        }
+
+        // The first word is always ASCII.
+        result[..1].make_ascii_uppercase();
+        Ok(result)
+    }
+}
+
+/// Appends `n` in words, like `one thousand two hundred thirty-four`.
+fn push_number(result: &mut String, n: u128) {
+    if n == 0 {
+        result.push_str(ONES[0]);
+        return;
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        groups.push((rest % 1000) as usize);
+        rest /= 1000;
+    }
+
+    let mut first = true;
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if !first {
+            result.push(' ');
+        }
+        first = false;
+        push_hundreds(result, group);
+        if scale > 0 {
+            result.push(' ');
+            result.push_str(SCALES[scale]);
+        }
+    }
+}
+
+/// Appends a number below 1000 in words.
+fn push_hundreds(result: &mut String, n: usize) {
+    let (hundreds, rest) = (n / 100, n % 100);
+    if hundreds > 0 {
+        result.push_str(ONES[hundreds]);
+        result.push_str(" hundred");
+        if rest > 0 {
+            result.push(' ');
+        }
+    }
+    if rest >= 20 {
+        result.push_str(TENS[rest / 10]);
+        if rest % 10 > 0 {
+            result.push('-');
+            result.push_str(ONES[rest % 10]);
+        }
+    } else if rest > 0 {
+        result.push_str(ONES[rest]);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(n: u128) -> String {
+        let mut result = String::new();
+        push_number(&mut result, n);
+        result
+    }
+
+    #[test]
+    fn number_test() {
+        assert_eq!(number(0), "zero");
+        assert_eq!(number(7), "seven");
+        assert_eq!(number(19), "nineteen");
+        assert_eq!(number(40), "forty");
+        assert_eq!(number(99), "ninety-nine");
+        assert_eq!(number(100), "one hundred");
+        assert_eq!(number(1_000_001), "one million one");
+        assert_eq!(number(12_000_345), "twelve million three hundred forty-five");
+        assert_eq!(number(1_000_000_000_000_000_000_000_000_000_000_000_000), "one undecillion");
+        assert!(number(u128::MAX).starts_with("three hundred forty undecillion two hundred eighty-two decillion"));
+    }
+
+    #[test]
+    fn fraction_test() {
+        let words = AmountWords::for_currency("usd").unwrap();
+        assert_eq!(words.format(0).unwrap(), "Zero and 00/100 dollars");
+        assert_eq!(words.format(1).unwrap(), "One and 00/100 dollars");
+        assert_eq!(words.format(1.05).unwrap(), "One and 05/100 dollars");
+        assert_eq!(words.format(0.999).unwrap(), "One and 00/100 dollars");
+        assert_eq!(words.format(100).unwrap(), "One hundred and 00/100 dollars");
+
+        let words = AmountWords::for_currency("KWD").unwrap();
+        assert_eq!(words.format(2.5).unwrap(), "Two and 500/1000 Kuwaiti Dinar");
+        let words = AmountWords::for_currency("JPY").unwrap();
+        assert_eq!(words.format(1).unwrap(), "One yen");
+        assert_eq!(words.format(1500).unwrap(), "One thousand five hundred yen");
+    }
+
+    #[test]
+    fn words_test() {
+        let mut words = AmountWords::for_currency("GBP").unwrap();
+        words.set_style(MinorStyle::Words);
+        assert_eq!(words.format(0.01).unwrap(), "Zero pounds and one penny");
+        assert_eq!(words.format(2).unwrap(), "Two pounds");
+        assert_eq!(words.format(-1.99).unwrap(), "Minus one pound and ninety-nine pence");
+
+        let mut words = AmountWords::new("widget", "widgets", "bit", "bits", 1);
+        words.set_style(MinorStyle::Words);
+        words.set_rounding_mode(RoundingMode::Ceiling);
+        assert_eq!(words.format(3.01).unwrap(), "Three widgets and one bit");
+    }
+
+    #[test]
+    fn range_test() {
+        let words = AmountWords::for_currency("USD").unwrap();
+        assert!(words.format(i128::MIN).unwrap().starts_with("Minus one hundred seventy undecillion"));
+        assert!(words.format(u128::MAX).unwrap().starts_with("Three hundred forty undecillion"));
+        assert_eq!(words.format(1e39), Err(WordsError::TooLarge));
+        assert_eq!(words.format(f64::INFINITY), Err(WordsError::NonFinite));
+        assert_eq!(AmountWords::for_currency("XYZ"), Err(CurrencyError::UnknownCode("XYZ".to_string())));
+    }
+}
@@ -1,10 +1,22 @@
-use accounting::Accounting;
+use accounting::{Accounting, Grouping, RoundingMode};
 use accounting::{unformat, UnformatError};
+use accounting::{Currency, Money, MoneyError};
+use accounting::TemplateError;
+use accounting::{AmountWords, MinorStyle};
+use accounting::{format_chinese_uppercase, parse_chinese_uppercase};
+use accounting::Compact;
+use accounting::{Percent, PercentUnit};
+use accounting::ExcelFormatError;
+use accounting::PatternError;
+use accounting::StrfmonError;
+use accounting::{LcMonetary, LcMonetaryError};
+use accounting::{AccountingJsFormat, AccountingJsSettings};
+use accounting::ConfigError;
 
 #[test]
 fn test_set_format() {
 	let mut ac = Accounting::new_from("$", 2);
-	ac.set_format("{v} {s}");
+	ac.set_format("{v} {s}").unwrap();
 	assert_eq!(ac.format_money(123456789.213123), "123,456,789.21 $");
 	assert_eq!(ac.format_money(-123456789.213123), "-123,456,789.21 $");
 	assert_eq!(ac.format_money(0), "0.00 $");
@@ -13,17 +25,86 @@ fn test_set_format() {
 #[test]
 fn test_set_format_zero() {
 	let mut ac = Accounting::default();
-	ac.set_format_zero("{s} --");
+	ac.set_format_zero("{s} --").unwrap();
 	assert_eq!(ac.format_money(0), "$ --");
 }
 
 #[test]
 fn test_set_format_neg() {
 	let mut ac = Accounting::new_from("$", 2);
-	ac.set_format_negative("{s}({v})");
+	ac.set_format_negative("{s}({v})").unwrap();
 	assert_eq!(ac.format_money(-123456789.213123), "$(123,456,789.21)");
 }
 
+#[test]
+fn test_set_format_escape() {
+	let mut ac = Accounting::new_from("$", 2);
+	ac.set_format("{{{v}}} {s}").unwrap();
+	assert_eq!(ac.format_money(12.5), "{12.50} $");
+	assert_eq!(ac.set_format("{s} {value}"), Err(TemplateError::UnknownPlaceholder("value".to_string())));
+	assert_eq!(ac.format_money(-12.5), "-{12.50} $");
+	assert_eq!(ac.format_money(-0.001), "{0.00} $");
+}
+
+#[test]
+fn test_set_format_placeholders() {
+	let mut ac = Accounting::for_currency("USD").unwrap();
+	ac.set_format("{c} {v}").unwrap();
+	assert_eq!(ac.format_money(1000), "USD 1,000.00");
+	ac.set_format("{v} {n}").unwrap();
+	ac.set_name("US dollars");
+	assert_eq!(ac.format_money(1000), "1,000.00 US dollars");
+	ac.set_format("{s}{nbsp}{sign}{v}").unwrap();
+	assert_eq!(ac.format_money(-1000), "$\u{a0}-1,000.00");
+	ac.set_format("{s}{sign}{v}").unwrap();
+	assert_eq!(ac.format_money(-5), "$-5.00");
+	ac.set_format("{v}{sign} {s}").unwrap();
+	assert_eq!(ac.format_money(-5), "5.00- $");
+}
+
+#[test]
+fn test_write_money() {
+	use std::io::Write;
+
+	let ac = Accounting::for_currency("USD").unwrap();
+	let mut out = std::io::BufWriter::new(Vec::new());
+	for value in [1.5, -1234.567, 0.0] {
+		ac.write_money_io(&mut out, value).unwrap();
+		out.write_all(b"\n").unwrap();
+	}
+	assert_eq!(out.into_inner().unwrap(), b"$1.50\n-$1,234.57\n$0.00\n");
+
+	let mut s = String::new();
+	ac.write_money(&mut s, -2.5).unwrap();
+	assert_eq!(s, "-$2.50");
+}
+
+#[test]
+fn test_display() {
+	let ac = Accounting::for_currency("USD").unwrap();
+	let row = format!("|{:<10}|{:>12}|{:^12}|", "coffee", ac.display(-3.5), ac.display(1234));
+	assert_eq!(row, "|coffee    |      -$3.50| $1,234.00  |");
+	assert_eq!(format!("{:*>8}", ac.display(0.05)), "***$0.05");
+}
+
+#[test]
+fn test_format_column() {
+	let mut ac = Accounting::for_currency("EUR").unwrap();
+	ac.set_format_negative("({s}{v})").unwrap();
+	let column = ac.format_column(&[1234567.891, -0.5, 42.0]);
+	assert_eq!(column, [
+		" €1.234.567,89 ",
+		"(€        0,50)",
+		" €       42,00 ",
+	]);
+
+	let ac = Accounting::new_from("$", 2);
+	assert_eq!(ac.format_column(&[5, -1234]), [" $    5.00", "-$1,234.00"]);
+	let mut ac = Accounting::new_from("€", 2);
+	ac.set_format("{v} {s}").unwrap();
+	assert_eq!(ac.format_column(&[1234, -5]), ["1,234.00 €", "   -5.00 €"]);
+}
+
 #[test]
 fn test_set_thousand_separator() {
 	let mut ac = Accounting::new_from("$", 2);
@@ -38,11 +119,59 @@ fn test_set_decimal_separator() {
 	assert_eq!(ac.format_money(123456789.213123), "$123,456,789'21")
 }
 
+#[test]
+fn test_set_grouping() {
+	let mut ac = Accounting::new_from("₹", 2);
+	ac.set_grouping(Grouping::indian());
+	assert_eq!(ac.format_money(123456789), "₹12,34,56,789.00");
+	assert_eq!(ac.format_money(-1234.5), "-₹1,234.50");
+}
+
+#[test]
+fn test_set_rounding_mode() {
+	let mut ac = Accounting::new_from("$", 2);
+	assert_eq!(ac.format_money(1.005), "$1.00");
+	assert_eq!(ac.format_money(1.015), "$1.02");
+	ac.set_rounding_mode(RoundingMode::HalfUp);
+	assert_eq!(ac.format_money(1.125), "$1.13");
+	ac.set_rounding_mode(RoundingMode::HalfDown);
+	assert_eq!(ac.format_money(1.125), "$1.12");
+	ac.set_rounding_mode(RoundingMode::TowardZero);
+	assert_eq!(ac.format_money(-1.999), "-$1.99");
+
+	ac.set_rounding_mode(RoundingMode::HalfUp);
+	assert_eq!(ac.format_money(1.005), "$1.01");
+	assert_eq!(ac.format_money(2.675), "$2.68");
+
+	#[cfg(feature="decimal")]
+	{
+		ac.set_rounding_mode(RoundingMode::HalfUp);
+		assert_eq!(ac.format_money(rust_decimal::Decimal::new(1125, 3)), "$1.13");
+	}
+}
+
+#[test]
+fn test_fraction_digits() {
+	let mut ac = Accounting::new_from("$", 2);
+	ac.set_max_fraction_digits(4);
+	ac.set_min_fraction_digits(0);
+	assert_eq!(ac.format_money(0.0045), "$0.0045");
+	assert_eq!(ac.format_money(12), "$12");
+	assert_eq!(ac.format_money(-12.50), "-$12.5");
+	ac.set_min_fraction_digits(2);
+	assert_eq!(ac.format_money(12), "$12.00");
+	assert_eq!(ac.format_money(0.0045), "$0.0045");
+	assert_eq!(ac.to_pattern(), "¤#,##0.00##");
+
+	#[cfg(feature="decimal")]
+	assert_eq!(ac.format_money(rust_decimal::Decimal::new(1200, 2)), "$12.00");
+}
+
 #[cfg(feature="decimal")]
 #[test]
 fn test_format_decimal_type() {
 	let mut ac = Accounting::new_from("$", 2);
-	ac.set_format("{s} {v}");
+	ac.set_format("{s} {v}").unwrap();
 	let x = rust_decimal::Decimal::new(0, 1);
     assert_eq!(ac.format_money(x), "$ 0.00"); 
 	let x = rust_decimal::Decimal::new(-123456789213, 3);
@@ -55,3 +184,219 @@ fn test_unformat() {
 	assert_eq!(unformat("EUR 111.145.000,33", 2, "eur"), Ok("111145000.33".to_string()));
 	assert_eq!(unformat("$45,567.10", 2, "zzz"), Err(UnformatError::NoLocaleFound));
 }
+
+#[test]
+fn test_money() {
+	let mut ac = Accounting::for_currency("USD").unwrap();
+	ac.set_format_negative("({s}{v})").unwrap();
+	let total = Money::from_minor(123456, "USD").unwrap()
+		.checked_sub(Money::from_major(2000, "USD").unwrap()).unwrap();
+	assert_eq!(ac.format_money_checked(&total).unwrap(), "($765.44)");
+	assert_eq!(
+		total.checked_add(Money::from_minor(1, "EUR").unwrap()),
+		Err(MoneyError::CurrencyMismatch { left: "USD".to_string(), right: "EUR".to_string() })
+	);
+
+	let ac = Accounting::for_currency("USD").unwrap();
+	assert_eq!(ac.format_money_checked(&total).unwrap(), "-$765.44");
+	assert_eq!(
+		Accounting::new_from("$", 2).format_money_checked(&total),
+		Err(MoneyError::CurrencyMismatch { left: "".to_string(), right: "USD".to_string() })
+	);
+	assert_eq!(
+		ac.format_money_checked(&Money::from_major(1500, "JPY").unwrap()),
+		Err(MoneyError::CurrencyMismatch { left: "USD".to_string(), right: "JPY".to_string() })
+	);
+}
+
+#[test]
+fn test_currency_registry() {
+	let usd = Currency::from_code("usd").unwrap();
+	assert_eq!(usd.code(), "USD");
+	assert_eq!(Currency::from_numeric(usd.numeric().unwrap()), Some(usd));
+	assert!(Currency::from_symbol(usd.symbol()).contains(&usd));
+	assert!(Currency::all().all(|c| Currency::from_code(c.code()) == Some(c)));
+}
+
+#[test]
+fn test_amount_words() {
+	let mut words = AmountWords::for_currency("USD").unwrap();
+	assert_eq!(words.format(1234.56).unwrap(), "One thousand two hundred thirty-four and 56/100 dollars");
+	words.set_style(MinorStyle::Words);
+	assert_eq!(words.format(1234.56).unwrap(), "One thousand two hundred thirty-four dollars and fifty-six cents");
+	assert_eq!(words.format(1_000_000.01).unwrap(), "One million dollars and one cent");
+}
+
+#[test]
+fn test_chinese_uppercase() {
+	let money = Money::from_minor(123456, "CNY").unwrap();
+	let s = format_chinese_uppercase(1234.56).unwrap();
+	assert_eq!(s, "人民币壹仟贰佰叁拾肆元伍角陆分");
+	assert_eq!(parse_chinese_uppercase(&s), Ok(money));
+	assert_eq!(format_chinese_uppercase(-30005).unwrap(), "人民币负叁万零伍元整");
+}
+
+#[test]
+fn test_compact() {
+	let mut ac = Accounting::for_currency("USD").unwrap();
+	ac.set_compact(Some(Compact::short()));
+	assert_eq!(ac.format_money(1234), "$1.2K");
+	assert_eq!(ac.format_money(3_400_000), "$3.4M");
+	assert_eq!(ac.format_money(-5e9), "-$5B");
+	assert_eq!(ac.format_money(999.999), "$1K");
+	assert_eq!(ac.format_money(12.5), "$12.50");
+	ac.set_compact(None);
+	assert_eq!(ac.format_money(1234), "$1,234.00");
+}
+
+#[test]
+fn test_percent() {
+	let mut ac = Accounting::for_currency("USD").unwrap();
+	ac.set_format_negative("({s}{v})").unwrap();
+	let percent = Percent::default();
+	assert_eq!(ac.format_percent(0.0525, &percent), "5.25%");
+	assert_eq!(ac.format_percent(-0.0525, &percent), "(5.25%)");
+	assert_eq!(ac.format_percent(12.3456, &percent), "1,234.56%");
+	assert_eq!(ac.format_percent(0.0525, &Percent::new(PercentUnit::PerMille)), "52.5‰");
+	assert_eq!(ac.format_percent(0.0525, &Percent::new(PercentUnit::BasisPoint)), "525 bp");
+
+	let ac = Accounting::for_locale("fr-FR", "EUR").unwrap();
+	let percent = Percent::for_locale("fr-FR", PercentUnit::Percent).unwrap();
+	assert_eq!(ac.format_percent(12.5, &percent), "1\u{202f}250\u{202f}%");
+}
+
+#[test]
+fn test_excel_format() {
+	let code = r##""$"#,##0.00_);[Red]("$"#,##0.00);"-""##;
+	let ac = Accounting::from_excel_format(code).unwrap();
+	assert_eq!(ac.format_money(1234.567), "$1,234.57 ");
+	assert_eq!(ac.format_money(-1234.567), "($1,234.57)");
+	assert_eq!(ac.format_money(0), "-");
+	let copy = Accounting::from_excel_format(&ac.to_excel_format()).unwrap();
+	assert_eq!(copy.format_money(-1234.567), "($1,234.57)");
+	assert_eq!(Accounting::from_excel_format("0%").err(), Some(ExcelFormatError::Unsupported("%".to_string())));
+}
+
+#[test]
+fn test_pattern() {
+	let ac = Accounting::from_pattern("¤#,##0.00;(¤#,##0.00)", "USD").unwrap();
+	assert_eq!(ac.format_money(-1234.5), "($1,234.50)");
+	assert_eq!(ac.to_pattern(), "¤#,##0.00;(¤#,##0.00)");
+	let ac = Accounting::from_pattern("#,##,##0.00 ¤¤", "INR").unwrap();
+	assert_eq!(ac.format_money(-12345678), "-1,23,45,678.00 INR");
+	let ac = Accounting::from_pattern("#,##0.00 EUR", "EUR").unwrap();
+	assert_eq!(ac.format_money(1234.5), "1,234.50 EUR");
+	let ac = Accounting::for_locale("de-CH", "CHF").unwrap();
+	assert_eq!(ac.to_pattern(), "¤\u{a0}#,##0.00;¤-#,##0.00");
+	assert_eq!(Accounting::from_pattern("0.00%", "USD").err(), Some(PatternError::Unsupported("%".to_string())));
+}
+
+#[test]
+fn test_strfmon() {
+	let ac = Accounting::for_currency("USD").unwrap();
+	assert_eq!(ac.strfmon("[%=*#10.2n] [%!i]", &[-1234.567, 5.0]).unwrap(), "[-$********1,234.57] [5.00]");
+	assert_eq!(ac.strfmon("%(#6n|%(#6n", &[12.5, -12.5]).unwrap(), " $     12.50|($     12.50)");
+	assert_eq!(ac.strfmon("%i", &[-1]).unwrap(), "-USD 1.00");
+	assert_eq!(ac.strfmon("%q", &[1]), Err(StrfmonError::InvalidConversion(0)));
+}
+
+#[test]
+fn test_lc_monetary() {
+	let source = std::fs::read_to_string("tests/data/lc_monetary/hi_IN").unwrap();
+	let ac = Accounting::from_lc_monetary(&source).unwrap();
+	assert_eq!(ac.format_money(-1234567.891), "-₹12,34,567.89");
+	let lc = LcMonetary::parse(&source).unwrap();
+	assert_eq!(lc.int_curr_symbol, "INR ");
+	assert_eq!(lc.mon_grouping, [3, 2]);
+	let source = std::fs::read_to_string("tests/data/lc_monetary/en_IN").unwrap();
+	assert_eq!(Accounting::from_lc_monetary(&source).err(), Some(LcMonetaryError::Copy("hi_IN".to_string())));
+}
+
+#[test]
+fn test_accounting_js_settings() {
+	let settings = AccountingJsSettings {
+		symbol: "€".to_string(),
+		format: AccountingJsFormat::Object {
+			pos: "%s %v".to_string(),
+			neg: Some("%s (%v)".to_string()),
+			zero: Some("%s  --".to_string()),
+		},
+		..AccountingJsSettings::default()
+	};
+	let ac = Accounting::from_accounting_js_settings(&settings);
+	assert_eq!(ac.format_money(1234.5), "€ 1,234.50");
+	assert_eq!(ac.format_money(-1234.5), "€ (1,234.50)");
+	assert_eq!(ac.format_money(0), "€  --");
+	assert_eq!(ac.to_accounting_js_settings(), settings);
+}
+
+#[test]
+fn test_accounting_builder() {
+	let ac = Accounting::builder()
+		.symbol("CHF")
+		.precision(2)
+		.thousand_separator("'")
+		.decimal_separator(".")
+		.format_positive("{s} {v}")
+		.format_negative("{s}-{v}")
+		.format_zero("{s} --")
+		.build()
+		.unwrap();
+	assert_eq!(ac.format_money(1234567.891), "CHF 1'234'567.89");
+	assert_eq!(ac.format_money(-5), "CHF-5.00");
+	assert_eq!(ac.format_money(0), "CHF --");
+
+	let err = Accounting::builder().thousand_separator(",").decimal_separator(",").build();
+	assert_eq!(err, Err(ConfigError::SameSeparators(",".to_string())));
+	let err = Accounting::builder().format_negative("({s})").build();
+	assert_eq!(err, Err(ConfigError::MissingValue("format_negative")));
+	let err = Accounting::builder().format("{s}{v}{x}").build();
+	assert_eq!(err, Err(ConfigError::Template("format_positive", TemplateError::UnknownPlaceholder("x".to_string()))));
+	assert_eq!(Accounting::builder().precision(40).build(), Err(ConfigError::Precision(40)));
+	assert_eq!(Accounting::builder().thousand_separator("0").build().unwrap_err().to_string(), "thousand separator \"0\" contains a digit");
+}
+
+#[cfg(feature="serde")]
+#[test]
+fn test_serde() {
+	let mut ac = Accounting::for_currency("EUR").unwrap();
+	ac.set_format_negative("({s}{v})").unwrap();
+	let json = serde_json::to_string(&ac).unwrap();
+	let copy: Accounting = serde_json::from_str(&json).unwrap();
+	assert_eq!(copy.format_money(-1234.5), "(€1.234,50)");
+	assert_eq!(copy, ac);
+
+	let err = serde_json::from_str::<Accounting>(r#"{"format_positive": "{s}"#).unwrap_err();
+	assert!(err.is_eof());
+	let err = serde_json::from_str::<Accounting>(r#"{"thousand": "'", "decimal": "'"}"#).unwrap_err();
+	assert!(err.to_string().contains("thousand and decimal separators"));
+}
+
+#[cfg(feature="serde")]
+#[test]
+fn test_money_string() {
+	use accounting::money_string::{self, Profile};
+
+	struct Usd;
+	impl Profile for Usd {
+		fn accounting() -> &'static Accounting {
+			static AC: std::sync::OnceLock<Accounting> = std::sync::OnceLock::new();
+			AC.get_or_init(|| Accounting::for_currency("USD").unwrap())
+		}
+	}
+
+	#[derive(Debug, serde::Serialize, serde::Deserialize)]
+	struct Item {
+		#[serde(serialize_with = "money_string::serialize::<Usd, _, _>")]
+		#[serde(deserialize_with = "money_string::deserialize::<Usd, _, _>")]
+		price: f64,
+		raw: f64,
+	}
+
+	let json = serde_json::to_string(&Item { price: 1234.5, raw: 1234.5 }).unwrap();
+	assert_eq!(json, r#"{"price":"$1,234.50","raw":1234.5}"#);
+	let item: Item = serde_json::from_str(r#"{"price":"-$12.30","raw":0}"#).unwrap();
+	assert_eq!(item.price, -12.3);
+	let err = serde_json::from_str::<Item>(r#"{"price":"$--1","raw":0}"#).unwrap_err();
+	assert!(err.to_string().starts_with("invalid money string \"$--1\": no format string matches"));
+}